@@ -1,18 +1,260 @@
-use crate::prelude::*;
+use std::{io::IsTerminal, path::Path};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::{
+    finder::{exact_match, pick},
+    prelude::*,
+};
 
 #[derive(Debug)]
 pub struct App {
     pub config: AppConfig,
-    pub command: Commands,
+    /// Source of the current time, for every command that depends on it.
+    pub clock: Box<dyn Clock>,
+    /// Options of this run, set on the command line.
+    pub options: RunOptions,
+}
+
+/// JSON output of a note a command resolved.
+#[derive(Debug, Serialize)]
+struct NoteOutput<'a> {
+    period: Periodical,
+    date: NaiveDate,
+    path: &'a Path,
+}
+
+/// JSON output of a listed note.
+#[derive(Debug, Serialize)]
+struct ListedNote<'a> {
+    path: &'a Path,
+    exists: bool,
 }
 
 impl App {
-    pub fn run(&self) -> Result<(), Status> {
-        match self.command {
+    pub fn run(&self, command: &Commands) -> Result<(), Status> {
+        match command {
             Commands::Reset => println!("Reseting config."),
             Commands::Periodical { time_span } => {
-                time_span.unwrap_or_default().open(&self.config)?
+                let today = NoteResolver::new(&self.config).today(self.clock.as_ref());
+                NoteWriter::new(&self.config, &self.options).open(
+                    time_span.unwrap_or_default(),
+                    today,
+                    self.clock.as_ref(),
+                )?
+            }
+            Commands::Next(args) => self.navigate(args, Direction::Next)?,
+            Commands::Prev(args) => self.navigate(args, Direction::Prev)?,
+            Commands::Up(args) => self.navigate(args, Direction::Up)?,
+            Commands::Down { file, existing } => self.list_children(file, *existing)?,
+            Commands::Which { file } => self.which(file)?,
+            Commands::Open { query } => self.open(&query.join(" "))?,
+            Commands::Recent { limit } => self.recent(*limit)?,
+            Commands::Last => self.last()?,
+            Commands::Stats { period } => self.stats(period.unwrap_or_default())?,
+            Commands::Export {
+                period,
+                from,
+                to,
+                format,
+            } => self.export(*period, *from, *to, *format)?,
+            Commands::Render { file } => self.render(file)?,
+            Commands::Browse => Browser::try_new(&self.config)?.run(
+                &self.config,
+                &self.options,
+                self.clock.as_ref(),
+            )?,
+            Commands::Calendar { month, year } => self.calendar(*month, *year)?,
+        }
+        Ok(())
+    }
+    /// Writes the note in the direction of the passed in note if it doesn't
+    /// exist, then either opens it or prints its path.
+    fn navigate(&self, args: &NoteArgs, direction: Direction) -> Result<(), Status> {
+        let (period, date) = direction.try_navigate(&self.config, &args.file)?;
+        if !args.print {
+            return period.open_at(&self.config, &self.options, date, self.clock.as_ref());
+        }
+        let path = period.try_create(&self.config, &self.options, date)?;
+        match self.options.is_json() {
+            true => {
+                let start = period.start_of(date.date_naive(), self.config.get_week_start());
+                let output = NoteOutput {
+                    period,
+                    date: start,
+                    path: &path,
+                };
+                println!(
+                    "{}",
+                    serde_json::to_string(&output).map_err(RuntimeError::Json)?
+                )
             }
+            false => println!("{}", path.display()),
+        }
+        Ok(())
+    }
+    /// Prints the paths of the notes one periodical smaller than the passed in note.
+    fn list_children(&self, file: &Path, existing: bool) -> Result<(), RuntimeError> {
+        let children = try_list_children(&self.config, file)?
+            .into_iter()
+            .filter(|path| !existing || path.exists())
+            .collect::<Vec<_>>();
+        match self.options.is_json() {
+            true => {
+                let output = children
+                    .iter()
+                    .map(|path| ListedNote {
+                        path,
+                        exists: path.exists(),
+                    })
+                    .collect::<Vec<_>>();
+                println!("{}", serde_json::to_string(&output)?)
+            }
+            false => children
+                .iter()
+                .for_each(|path| println!("{}", path.display())),
+        }
+        Ok(())
+    }
+    /// Prints the periodical and the start of the period the note belongs to.
+    fn which(&self, file: &Path) -> Result<(), RuntimeError> {
+        let path = self.config.try_resolve_note_path(file)?;
+        let Some((period, date)) = self.config.try_parse_note_path(&path)? else {
+            return Err(RuntimeError::NotPeriodical(path));
+        };
+        match self.options.is_json() {
+            true => {
+                let output = NoteOutput {
+                    period,
+                    date,
+                    path: &path,
+                };
+                println!("{}", serde_json::to_string(&output)?)
+            }
+            false => println!("{period} {date}"),
+        }
+        Ok(())
+    }
+    /// Opens the best match of the query, or asks which one to open
+    /// if no match is exact.
+    /// Fails if several notes match exactly.
+    fn open(&self, query: &str) -> Result<(), RuntimeError> {
+        let found = NoteFinder::new(&self.config).try_find(query, self.clock.as_ref())?;
+        let note = match found.as_slice() {
+            [] => return Err(RuntimeError::NoMatch(query.to_string())),
+            [note] => note,
+            _ => match exact_match(&found, query)? {
+                Some(note) => note,
+                None => {
+                    let mut input = std::io::stdin().lock();
+                    match pick(&found, &self.config, &mut input, &mut std::io::stderr())? {
+                        Some(note) => note,
+                        None => return Ok(()),
+                    }
+                }
+            },
+        };
+        match self.options.is_dry_run() {
+            true => DryRunReport::open_note(note.get_path()).print(&self.options)?,
+            false => open_in_editor(&self.config, note.get_path())?,
+        }
+        Ok(())
+    }
+    /// Prints the paths of the most recently opened notes.
+    fn recent(&self, limit: usize) -> Result<(), Status> {
+        let history = self.options.get_history().ok_or(ConfigError::StateDir)?;
+        let recent = history.try_recent(limit, self.clock.now())?;
+        match self.options.is_json() {
+            true => println!(
+                "{}",
+                serde_json::to_string(&recent).map_err(RuntimeError::Json)?
+            ),
+            false => recent
+                .iter()
+                .for_each(|entry| println!("{}", entry.get_path().display())),
+        }
+        Ok(())
+    }
+    /// Reopens the most recently opened note.
+    /// Notes whose path changed with the config since are opened as they were.
+    fn last(&self) -> Result<(), Status> {
+        let history = self.options.get_history().ok_or(ConfigError::StateDir)?;
+        let recent = history.try_recent(1, self.clock.now())?;
+        let entry = recent.first().ok_or(RuntimeError::NoHistory)?;
+        let (period, date) = (
+            entry.get_period(),
+            self.config.at_midnight(entry.get_date()),
+        );
+        if self.config.try_format_absolute_note_path(period, date)? == entry.get_path() {
+            return period.open_at(&self.config, &self.options, date, self.clock.as_ref());
+        }
+        match self.options.is_dry_run() {
+            true => DryRunReport::open_note(entry.get_path()).print(&self.options)?,
+            false => open_in_editor(&self.config, entry.get_path())?,
+        }
+        Ok(())
+    }
+    /// Prints the statistics of the periodical, with streaks up to today.
+    fn stats(&self, period: Periodical) -> Result<(), RuntimeError> {
+        let today = NoteResolver::new(&self.config).today(self.clock.as_ref());
+        let stats = Stats::try_new(&self.config, period, today)?;
+        match self.options.is_json() {
+            true => println!("{}", stats.to_json()?),
+            false => println!("{stats}"),
+        }
+        Ok(())
+    }
+    /// Prints the notes of the periodical from the date up to the other date,
+    /// or today, as a single document.
+    fn export(
+        &self,
+        period: Periodical,
+        from: NaiveDate,
+        to: Option<NaiveDate>,
+        format: ExportFormat,
+    ) -> Result<(), RuntimeError> {
+        let to = to.unwrap_or(NoteResolver::new(&self.config).today(self.clock.as_ref()));
+        let export = Export::try_new(&self.config, period, from, to)?;
+        print!("{}", export.render(format));
+        Ok(())
+    }
+    /// Prints the note rendered as HTML.
+    fn render(&self, file: &Path) -> Result<(), RuntimeError> {
+        let path = self.config.try_resolve_note_path(file)?;
+        print!(
+            "{}",
+            Renderer::try_new(&self.config)?.try_render_note(&path)?
+        );
+        Ok(())
+    }
+    /// Prints the calendar of the month, or of its year, defaulting to today's.
+    /// Colors are only used when printing to a terminal and NO_COLOR isn't set.
+    fn calendar(&self, month: Option<NaiveDate>, year: bool) -> Result<(), RuntimeError> {
+        let date = month.unwrap_or(NoteResolver::new(&self.config).today(self.clock.as_ref()));
+        let period = match year {
+            true => Periodical::Year,
+            false => Periodical::Month,
+        };
+        let calendar = Calendar::try_new(&self.config, period, date)?;
+        match self.options.is_json() {
+            true => println!("{}", calendar.to_json()?),
+            false => {
+                let color =
+                    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+                println!("{}", calendar.render(color))
+            }
+        }
+        Ok(())
+    }
+    /// Prints the resolved configuration in the requested output format.
+    /// Defaults to a human readable listing.
+    pub fn show_config(&self, toml: bool) -> Result<(), RuntimeError> {
+        let resolved = ResolvedConfig::try_new(&self.config, self.config.now(self.clock.as_ref()))?;
+        match (self.options.is_json(), toml) {
+            (true, _) => println!("{}", resolved.to_json()?),
+            (_, true) => print!("{}", resolved.to_toml()?),
+            _ => println!("{resolved}"),
         }
         Ok(())
     }
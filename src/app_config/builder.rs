@@ -0,0 +1,183 @@
+//! Builder for constructing an AppConfig in code, without a TOML file.
+use std::{collections::HashMap, path::PathBuf};
+
+use chrono::NaiveTime;
+
+use crate::{periodic_config::PeriodConfig, prelude::*, time_config::DayStart};
+
+/// Builds an AppConfig field by field.
+/// Unset fields fall back to the same defaults as an empty config file.
+#[derive(Debug, Default)]
+pub struct AppConfigBuilder {
+    vault: PathBuf,
+    periodical: HashMap<Periodical, PeriodConfig>,
+    time: TimeConfig,
+    git: GitConfig,
+    hooks: HookConfig,
+    rollup: RollupConfig,
+}
+
+impl AppConfig {
+    /// Starts building an AppConfig for the vault directory.
+    pub fn builder(vault: impl Into<PathBuf>) -> AppConfigBuilder {
+        AppConfigBuilder {
+            vault: vault.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl AppConfigBuilder {
+    fn period(&mut self, period: Periodical) -> &mut PeriodConfig {
+        self.periodical.entry(period).or_default()
+    }
+    /// Sets the periodical's directory, relative to the vault.
+    pub fn dir(mut self, period: Periodical, dir: impl Into<String>) -> Self {
+        self.period(period).dir = Some(dir.into());
+        self
+    }
+    /// Sets the periodical's file name format.
+    pub fn fmt(mut self, period: Periodical, fmt: impl Into<String>) -> Self {
+        self.period(period).fmt = Some(fmt.into());
+        self
+    }
+    /// Sets the periodical's template file, relative to the vault.
+    pub fn template(mut self, period: Periodical, template: impl Into<String>) -> Self {
+        self.period(period).template = Some(template.into());
+        self
+    }
+    /// Sets the day weeks start on.
+    pub fn week_start(mut self, week_start: WeekStart) -> Self {
+        self.period(Periodical::Week).week_start = Some(week_start);
+        self
+    }
+    /// Sets the scheme weeks are numbered with.
+    pub fn week_numbering(mut self, week_numbering: WeekNumbering) -> Self {
+        self.period(Periodical::Week).week_numbering = Some(week_numbering);
+        self
+    }
+    /// Sets the time of day a new day starts at.
+    pub fn day_starts_at(mut self, time: NaiveTime) -> Self {
+        self.time.day_starts_at = Some(DayStart(time));
+        self
+    }
+    /// Sets the timezone the current date is taken in.
+    pub fn timezone(mut self, timezone: Timezone) -> Self {
+        self.time.timezone = Some(timezone);
+        self
+    }
+    /// Sets whether opened notes are committed to git after editing.
+    pub fn git_auto_commit(mut self, auto_commit: bool) -> Self {
+        self.git.auto_commit = Some(auto_commit);
+        self
+    }
+    /// Sets the git commit message template.
+    pub fn git_message(mut self, message: impl Into<String>) -> Self {
+        self.git.message = Some(message.into());
+        self
+    }
+    /// Sets the shell command run for the hook.
+    pub fn hook(mut self, hook: Hook, command: impl Into<String>) -> Self {
+        let command = Some(command.into());
+        match hook {
+            Hook::PreCreate => self.hooks.pre_create = command,
+            Hook::PostCreate => self.hooks.post_create = command,
+            Hook::PostEdit => self.hooks.post_edit = command,
+        }
+        self
+    }
+    /// Sets whether new week, month and year notes list their children.
+    pub fn rollup(mut self, enabled: bool) -> Self {
+        self.rollup.enabled = Some(enabled);
+        self
+    }
+    /// Sets the heading the roll-up links go under.
+    pub fn rollup_heading(mut self, heading: impl Into<String>) -> Self {
+        self.rollup.heading = Some(heading.into());
+        self
+    }
+    /// Sets whether roll-up links mark which notes already exist.
+    pub fn rollup_mark_existing(mut self, mark_existing: bool) -> Self {
+        self.rollup.mark_existing = Some(mark_existing);
+        self
+    }
+    /// Attempts to build the AppConfig.
+    /// Returns an error if the vault isn't an existing directory.
+    pub fn try_build(self) -> Result<AppConfig, ConfigError> {
+        if !self.vault.is_dir() {
+            return Err(ConfigError::InvalidDir(self.vault));
+        }
+        Ok(AppConfig {
+            vault: self.vault,
+            periodical: self.periodical,
+            time: self.time,
+            git: self.git,
+            hooks: self.hooks,
+            rollup: self.rollup,
+            config_file: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_config::TomlConfig;
+
+    #[test]
+    fn test_builder_matches_toml() -> anyhow::Result<()> {
+        let desc = "Test building a config in code matches deserializing it";
+        let s = r###"
+            [vault]
+            dir = "."
+            [periodical.day]
+            dir = "day"
+            template = "templates/day.md"
+            [periodical.week]
+            fmt = "{start}"
+            week_start = "sunday"
+            week_numbering = "us"
+            [time]
+            day_starts_at = "04:00"
+            timezone = "Europe/Rome"
+            [git]
+            auto_commit = true
+            message = "daily: {note}"
+            [hooks]
+            post_edit = "true"
+            [rollup]
+            enabled = true
+            heading = "## Days"
+            mark_existing = true
+        "###;
+        let want = AppConfig::try_from(toml::from_str::<TomlConfig>(s)?)?;
+        let got = AppConfig::builder(".")
+            .dir(Periodical::Day, "day")
+            .template(Periodical::Day, "templates/day.md")
+            .fmt(Periodical::Week, "{start}")
+            .week_start(WeekStart::Sunday)
+            .week_numbering(WeekNumbering::Us)
+            .day_starts_at(NaiveTime::from_hms_opt(4, 0, 0).unwrap())
+            .timezone("Europe/Rome".parse().map_err(anyhow::Error::msg)?)
+            .git_auto_commit(true)
+            .git_message("daily: {note}")
+            .hook(Hook::PostEdit, "true")
+            .rollup(true)
+            .rollup_heading("## Days")
+            .rollup_mark_existing(true)
+            .try_build()?;
+
+        assert_eq!(want, got, "{desc}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_invalid_vault() {
+        let desc = "Test building a config validates the vault";
+        let got = AppConfig::builder("./not-a-vault").try_build();
+        assert!(
+            matches!(got, Err(ConfigError::InvalidDir(_))),
+            "{desc}: {got:?}"
+        );
+    }
+}
@@ -3,15 +3,18 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use serde::Deserialize;
+use strum::IntoEnumIterator;
+use toml::Spanned;
 
 use crate::{periodic_config::PeriodConfig, prelude::*};
 
 pub mod prelude {
-    pub use super::AppConfig;
+    pub use super::{AppConfig, builder::AppConfigBuilder};
 }
 
+mod builder;
 #[cfg(test)]
 mod test_cases;
 #[cfg(test)]
@@ -21,12 +24,17 @@ mod tests;
 pub struct AppConfig {
     vault: PathBuf,
     periodical: HashMap<Periodical, PeriodConfig>,
+    time: TimeConfig,
+    git: GitConfig,
+    hooks: HookConfig,
+    rollup: RollupConfig,
+    config_file: Option<PathBuf>,
 }
 
 impl AppConfig {
     /// Attempts to get the parent directory of a note file.
     /// Returns the valut root if none is configured.
-    fn get_parent_dir(&self, period: Periodical) -> PathBuf {
+    pub(crate) fn get_parent_dir(&self, period: Periodical) -> PathBuf {
         let dir = || {
             let config = self.periodical.get(&period)?;
             config.get_parent_dir()
@@ -39,13 +47,64 @@ impl AppConfig {
     pub fn get_vault_root(&self) -> &Path {
         &self.vault
     }
+    /// Getter for the configuration file this AppConfig was read from.
+    /// Returns None if the config was not built from a file.
+    pub fn get_config_file(&self) -> Option<&Path> {
+        self.config_file.as_deref()
+    }
+    /// Getter for the configuration of when and where a day starts.
+    pub fn get_time_config(&self) -> &TimeConfig {
+        &self.time
+    }
+    /// Getter for the configuration of committing notes to git.
+    pub fn get_git_config(&self) -> &GitConfig {
+        &self.git
+    }
+    /// Getter for the shell commands run while opening notes.
+    pub fn get_hook_config(&self) -> &HookConfig {
+        &self.hooks
+    }
+    /// Getter for the links to smaller periodicals written into new notes.
+    pub fn get_rollup_config(&self) -> &RollupConfig {
+        &self.rollup
+    }
+    /// Returns the clock's current date time in the configured timezone,
+    /// shifted by the configured start of day.
+    pub fn now(&self, clock: &dyn Clock) -> DateTime<FixedOffset> {
+        self.time.now(clock)
+    }
+    /// Getter for the day weeks start on, configured for `Periodical::Week`.
+    pub fn get_week_start(&self) -> WeekStart {
+        self.periodical
+            .get(&Periodical::Week)
+            .map(|config| config.get_week_start())
+            .unwrap_or_default()
+    }
+    /// Getter for the week numbering scheme, configured for `Periodical::Week`.
+    pub fn get_week_numbering(&self) -> WeekNumbering {
+        self.periodical
+            .get(&Periodical::Week)
+            .map(|config| config.get_week_numbering())
+            .unwrap_or_default()
+    }
+    /// Anchors a date at midnight in the configured timezone.
+    pub fn at_midnight(&self, date: NaiveDate) -> DateTime<FixedOffset> {
+        self.time.at_midnight(date)
+    }
+    /// Returns the date format string used for the periodical's file names.
+    pub fn get_fmt(&self, period: Periodical) -> &str {
+        match self.periodical.get(&period) {
+            Some(config) => config.get_fmt(period),
+            None => period.default_fmt(),
+        }
+    }
     /// Formats the date with interior periodical configurations
     /// Uses the default formatting configurations if none exists.
-    pub fn format_date(&self, period: Periodical, date: DateTime<Local>) -> String {
+    pub fn format_date(&self, period: Periodical, date: DateTime<FixedOffset>) -> String {
         self.periodical
             .get(&period)
             .unwrap_or(&PeriodConfig::default())
-            .format(period, date)
+            .format(period, date, self.get_week_start())
     }
     /// Attempts to format and return the absolute path of a note file.
     /// Returns `${VAULT_ROOT}/${DEFAULT_FILE_NAME_FORMAT}.md` if
@@ -53,7 +112,7 @@ impl AppConfig {
     pub fn try_format_absolute_note_path(
         &self,
         period: Periodical,
-        date: DateTime<Local>,
+        date: DateTime<FixedOffset>,
     ) -> Result<PathBuf, RuntimeError> {
         let parent_dir = self.get_parent_dir(period);
 
@@ -62,7 +121,7 @@ impl AppConfig {
                 .periodical
                 .get(&period)
                 .unwrap_or(&PeriodConfig::default())
-                .format(period, date);
+                .format(period, date, self.get_week_start());
 
             format!("{name}.md")
         };
@@ -73,6 +132,44 @@ impl AppConfig {
         }
         Ok(full_path)
     }
+    /// Attempts to find the periodical and the first day of its period
+    /// a note path was formatted from.
+    /// Returns None if the path doesn't match any periodical's dir and format.
+    pub fn try_parse_note_path(
+        &self,
+        path: &Path,
+    ) -> Result<Option<(Periodical, NaiveDate)>, RuntimeError> {
+        let path = std::path::absolute(path)?;
+        for period in Periodical::iter() {
+            let parent_dir = std::path::absolute(self.get_parent_dir(period))?;
+            let name = || {
+                let relative = path.strip_prefix(&parent_dir).ok()?;
+                relative.to_str()?.strip_suffix(".md")
+            };
+            let Some(name) = name() else {
+                continue;
+            };
+            let date = self
+                .periodical
+                .get(&period)
+                .unwrap_or(&PeriodConfig::default())
+                .parse(period, name, self.get_week_start());
+            if let Some(date) = date {
+                return Ok(Some((period, date)));
+            }
+        }
+        Ok(None)
+    }
+    /// Resolves a note path passed in by the user.
+    /// Relative paths that don't exist from the current directory
+    /// are resolved against the vault root.
+    pub fn try_resolve_note_path(&self, path: &Path) -> Result<PathBuf, RuntimeError> {
+        let path = match path.is_relative() && !path.exists() {
+            true => self.get_vault_root().join(path),
+            false => path.to_path_buf(),
+        };
+        Ok(std::path::absolute(path)?)
+    }
     /// Attempts to get the absolute path of a possible template file.
     pub fn try_format_absolute_template_path(
         &self,
@@ -95,11 +192,15 @@ impl AppConfig {
 pub struct TomlConfig {
     vault: TomlVault,
     periodical: Option<TomlPeriod>,
+    time: Option<TimeConfig>,
+    git: Option<GitConfig>,
+    hooks: Option<HookConfig>,
+    rollup: Option<RollupConfig>,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 struct TomlVault {
-    dir: PathBuf,
+    dir: Spanned<PathBuf>,
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq)]
@@ -110,23 +211,37 @@ impl TryFrom<TomlConfig> for AppConfig {
 
     fn try_from(value: TomlConfig) -> Result<Self, Self::Error> {
         // validate vauld diretory
-        let vault = value.vault.dir.as_path();
+        let vault = value.vault.dir.into_inner();
         if !vault.is_dir() {
-            return Err(ConfigError::InvalidDir(vault.to_owned()));
+            return Err(ConfigError::InvalidDir(vault));
         }
         Ok(Self {
-            vault: value.vault.dir,
+            vault,
             periodical: value.periodical.unwrap_or_default().0,
+            time: value.time.unwrap_or_default(),
+            git: value.git.unwrap_or_default(),
+            hooks: value.hooks.unwrap_or_default(),
+            rollup: value.rollup.unwrap_or_default(),
+            config_file: None,
         })
     }
 }
 
+impl TomlConfig {
+    /// Attempts to deserialize the source of a config file.
+    /// Errors point at the offending TOML in the file.
+    fn try_parse(source: &str, path: &Path) -> Result<Self, ConfigError> {
+        toml::from_str::<TomlConfig>(source)
+            .map_err(|e| Diagnostic::from_toml(&e, path, source).into())
+    }
+}
+
 impl TryFrom<ConfigFile> for TomlConfig {
     type Error = ConfigError;
 
     fn try_from(value: ConfigFile) -> Result<Self, Self::Error> {
-        let bytes = std::fs::read(value.0)?;
-        Ok(toml::from_slice::<TomlConfig>(&bytes)?)
+        let source = std::fs::read_to_string(&value.0)?;
+        TomlConfig::try_parse(&source, &value.0)
     }
 }
 
@@ -134,7 +249,27 @@ impl TryFrom<ConfigFile> for AppConfig {
     type Error = ConfigError;
 
     fn try_from(value: ConfigFile) -> Result<Self, Self::Error> {
-        let toml = TomlConfig::try_from(value)?;
-        AppConfig::try_from(toml)
+        let config_file = std::path::absolute(&value.0)?;
+        let source = std::fs::read_to_string(&config_file)?;
+        let toml = TomlConfig::try_parse(&source, &config_file)?;
+        let span = toml.vault.dir.span();
+
+        let config = AppConfig::try_from(toml).map_err(|e| match e {
+            ConfigError::InvalidDir(ref dir) => {
+                let hint = match dir.is_relative() {
+                    true => "vault dir is relative; it's resolved against CWD".to_string(),
+                    false => "create the directory, or point the vault dir at an existing one"
+                        .to_string(),
+                };
+                Diagnostic::new(e.to_string(), &config_file, &source, Some(span))
+                    .with_hint(hint)
+                    .into()
+            }
+            e => e,
+        })?;
+        Ok(Self {
+            config_file: Some(config_file),
+            ..config
+        })
     }
 }
@@ -43,6 +43,14 @@ dir = \"period/month\"
 fmt = \"%Y\"
 template = \"year.md\"";
 
+/// Test case that configures when and where a day starts.
+pub const CASE_TIME: &str = "[vault]
+dir = \"./vaults\"
+
+[time]
+day_starts_at = \"04:00\"
+timezone = \"Europe/Rome\"";
+
 /// Test case supplies empty periodic configuration
 pub const PERIODIC_CASE_DEFAULT: &str = "";
 
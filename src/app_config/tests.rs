@@ -3,7 +3,7 @@
 //! Test have to manually construct test AppConfig cases to maintain
 //! functional purity, as conversion between TomlConfig -> AppConfig
 //! performs validation using system state.
-use chrono::Local;
+use chrono::{Local, TimeZone};
 
 use super::{test_cases::*, *};
 
@@ -18,6 +18,7 @@ fn test_parent_dir() -> anyhow::Result<()> {
         let config = AppConfig {
             vault: want.clone(),
             periodical: HashMap::new(),
+            ..Default::default()
         };
         let got = config.get_vault_root();
         assert_eq!(want, got, "{desc}");
@@ -45,8 +46,9 @@ fn test_absoulute_note_path() -> anyhow::Result<()> {
         let config = AppConfig {
             vault: "./vaults".into(),
             periodical: toml::de::from_str::<TomlPeriod>(s)?.0,
+            ..Default::default()
         };
-        let got = config.try_format_absolute_note_path(*period, Local::now())?;
+        let got = config.try_format_absolute_note_path(*period, Local::now().fixed_offset())?;
         let file_name = Local::now().format(want).to_string();
 
         assert!(got.to_string_lossy().contains(&file_name), "{desc}");
@@ -55,6 +57,72 @@ fn test_absoulute_note_path() -> anyhow::Result<()> {
     })
 }
 
+#[test]
+fn test_parse_note_path() -> anyhow::Result<()> {
+    let test_cases = [
+        (
+            "vaults/day/12-30-2025.md",
+            Some((Periodical::Day, "2025-12-30")),
+            "Test configured day dir and format",
+        ),
+        (
+            "vaults/period/week/2026-W01.md",
+            Some((Periodical::Week, "2025-12-29")),
+            "Test configured week dir",
+        ),
+        (
+            "vaults/2025-12.md",
+            Some((Periodical::Month, "2025-12-01")),
+            "Test unconfigured month in the vault root",
+        ),
+        ("vaults/day/2025-12-30.md", None, "Test wrong format"),
+        ("vaults/period/2026-W01.md", None, "Test wrong dir"),
+        ("elsewhere/2025.md", None, "Test outside of the vault"),
+    ];
+    let config = AppConfig {
+        vault: "./vaults".into(),
+        periodical: toml::de::from_str::<TomlPeriod>(PERIODIC_CASE_OPTIONS)?.0,
+        ..Default::default()
+    };
+    test_cases.iter().try_for_each(|(path, want, desc)| {
+        let got = config.try_parse_note_path(Path::new(path))?;
+        let got = got.map(|(period, date)| (period, date.to_string()));
+        let want = want.map(|(period, date)| (period, date.to_string()));
+
+        assert_eq!(want, got, "{desc}");
+        anyhow::Ok(())
+    })
+}
+
+#[test]
+fn test_week_of_month() -> anyhow::Result<()> {
+    let test_cases = [
+        (
+            "",
+            "2026-01-04 W1",
+            "Test weeks of the month start on Monday",
+        ),
+        (
+            "[week]\nweek_start = \"sunday\"",
+            "2026-01-04 W2",
+            "Test the week's week start counts for other periodicals",
+        ),
+    ];
+    // a Sunday, in the first week of January if weeks start on Monday
+    let date = Local.with_ymd_and_hms(2026, 1, 4, 0, 0, 0).unwrap();
+    test_cases.iter().try_for_each(|(week, want, desc)| {
+        let s = format!("{week}\n[day]\nfmt = \"%Y-%m-%d W{{week_of_month}}\"");
+        let config = AppConfig {
+            vault: "./vaults".into(),
+            periodical: toml::de::from_str::<TomlPeriod>(&s)?.0,
+            ..Default::default()
+        };
+        let got = config.format_date(Periodical::Day, date.fixed_offset());
+        assert_eq!(want.to_string(), got, "{desc}");
+        anyhow::Ok(())
+    })
+}
+
 #[test]
 fn test_absolute_template_path() -> anyhow::Result<()> {
     let test_cases = [
@@ -81,6 +149,7 @@ fn test_absolute_template_path() -> anyhow::Result<()> {
         let config = AppConfig {
             vault: "./vaults".into(),
             periodical: toml::de::from_str::<TomlPeriod>(s)?.0,
+            ..Default::default()
         };
         let got = config.try_format_absolute_template_path(*period)?;
 
@@ -130,7 +199,7 @@ fn test_de_vault() -> anyhow::Result<()> {
     test_cases.iter().try_for_each(|(s, want, desc)| {
         let got = toml::from_str::<TomlConfig>(s)?;
 
-        assert_eq!(got.vault.dir, PathBuf::from(want), "{desc}");
+        assert_eq!(*got.vault.dir.get_ref(), PathBuf::from(want), "{desc}");
         anyhow::Ok(())
     })
 }
@@ -219,17 +288,55 @@ fn test_de_filename() -> anyhow::Result<()> {
             .periodical
             .unwrap_or_default()
             .0;
-        let date = Local::now();
-        let got = got
-            .get(&period)
-            .unwrap_or(&PeriodConfig::default())
-            .format(period, date);
+        let date = Local::now().fixed_offset();
+        let got = got.get(&period).unwrap_or(&PeriodConfig::default()).format(
+            period,
+            date,
+            WeekStart::default(),
+        );
 
         assert_eq!(want.to_string(), got, "{desc}");
         anyhow::Ok(())
     })
 }
 
+#[test]
+fn test_de_time() -> anyhow::Result<()> {
+    let test_cases = [
+        (
+            CASE_DEFAULTS,
+            "00:00",
+            None,
+            "Case default: Test unset time config",
+        ),
+        (
+            CASE_TIME,
+            "04:00",
+            Some("Europe/Rome"),
+            "Case time: Test set day start and timezone",
+        ),
+    ];
+    test_cases
+        .iter()
+        .try_for_each(|(s, want_start, want_tz, desc)| {
+            let got = toml::de::from_str::<TomlConfig>(s)?
+                .time
+                .unwrap_or_default();
+
+            assert_eq!(
+                *want_start,
+                got.get_day_start().format("%H:%M").to_string(),
+                "{desc}"
+            );
+            assert_eq!(
+                want_tz.map(|tz| tz.to_string()),
+                got.get_timezone().map(|tz| tz.to_string()),
+                "{desc}"
+            );
+            anyhow::Ok(())
+        })
+}
+
 #[test]
 fn test_invalid_vault() -> anyhow::Result<()> {
     let desc =
@@ -0,0 +1,419 @@
+use std::{cmp::Reverse, path::PathBuf};
+
+use chrono::NaiveDate;
+use fuzzy_matcher::{FuzzyMatcher, skim::SkimMatcherV2};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::{
+        event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+        terminal::{EnterAlternateScreen, enable_raw_mode},
+    },
+    layout::{Constraint, Layout},
+    style::{Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::Browser;
+}
+
+/// Periodical note listed by the browser.
+#[derive(Debug, Clone, PartialEq)]
+struct BrowsedNote {
+    period: Periodical,
+    date: NaiveDate,
+    /// File name without the extension, which the filter matches against.
+    name: String,
+    path: PathBuf,
+}
+
+/// What the browser's caller should do after a key press.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrowseAction {
+    Continue,
+    /// Opens the note of the periodical and date in the editor.
+    Open(Periodical, NaiveDate),
+    Quit,
+}
+
+/// Terminal UI listing the vault's periodical notes, grouped by periodical,
+/// next to a preview of the selected note.
+#[derive(Debug)]
+pub struct Browser {
+    /// Every note, grouped by periodical and newest first.
+    notes: Vec<BrowsedNote>,
+    filter: String,
+    /// Indices of the notes matching the filter, in display order.
+    matches: Vec<usize>,
+    /// Index into the matches.
+    selected: usize,
+}
+
+impl Browser {
+    /// Attempts to collect every periodical note in the vault.
+    pub fn try_new(config: &AppConfig) -> Result<Self, RuntimeError> {
+        let mut notes = list_notes(config.get_vault_root())?
+            .into_iter()
+            .map(|path| {
+                let note = config.try_parse_note_path(&path)?.map(|(period, date)| {
+                    let name = path
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    BrowsedNote {
+                        period,
+                        date,
+                        name,
+                        path,
+                    }
+                });
+                Ok(note)
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<_>, RuntimeError>>()?;
+        notes.sort_by_key(|note| (note.period, Reverse(note.date)));
+
+        let mut browser = Self {
+            notes,
+            filter: String::new(),
+            matches: vec![],
+            selected: 0,
+        };
+        browser.update_matches();
+        Ok(browser)
+    }
+    /// Runs the browser in the terminal until it's quit.
+    /// The terminal is handed to the editor while a note is open.
+    pub fn run(
+        mut self,
+        config: &AppConfig,
+        options: &RunOptions,
+        clock: &dyn Clock,
+    ) -> Result<(), Status> {
+        let mut terminal = ratatui::init();
+        let result = self.event_loop(&mut terminal, config, options, clock);
+        ratatui::restore();
+        result
+    }
+    fn event_loop(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        config: &AppConfig,
+        options: &RunOptions,
+        clock: &dyn Clock,
+    ) -> Result<(), Status> {
+        loop {
+            terminal
+                .draw(|frame| self.draw(frame))
+                .map_err(RuntimeError::Io)?;
+            let Event::Key(key) = event::read().map_err(RuntimeError::Io)? else {
+                continue;
+            };
+            match self.handle_key(key) {
+                BrowseAction::Continue => (),
+                BrowseAction::Quit => return Ok(()),
+                BrowseAction::Open(period, date) => {
+                    ratatui::try_restore().map_err(RuntimeError::Io)?;
+                    let date = config.at_midnight(date);
+                    let opened = period.open_at(config, options, date, clock);
+                    // the browser comes back even if the editor failed
+                    enable_raw_mode().map_err(RuntimeError::Io)?;
+                    ratatui::crossterm::execute!(std::io::stdout(), EnterAlternateScreen)
+                        .map_err(RuntimeError::Io)?;
+                    terminal.clear().map_err(RuntimeError::Io)?;
+                    opened?;
+                }
+            }
+        }
+    }
+    /// Updates the filter or selection for the key.
+    /// Typing filters, the arrow keys or ctrl+n/ctrl+p select,
+    /// enter opens, and escape clears the filter, quitting if it's empty.
+    pub fn handle_key(&mut self, key: KeyEvent) -> BrowseAction {
+        if key.kind != KeyEventKind::Press {
+            return BrowseAction::Continue;
+        }
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('c') if ctrl => return BrowseAction::Quit,
+            KeyCode::Esc if self.filter.is_empty() => return BrowseAction::Quit,
+            KeyCode::Esc => {
+                self.filter.clear();
+                self.update_matches();
+            }
+            KeyCode::Enter => {
+                if let Some(note) = self.get_selected() {
+                    return BrowseAction::Open(note.period, note.date);
+                }
+            }
+            KeyCode::Up => self.select_prev(),
+            KeyCode::Char('p' | 'k') if ctrl => self.select_prev(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::Char('n' | 'j') if ctrl => self.select_next(),
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.update_matches();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.filter.push(c);
+                self.update_matches();
+            }
+            _ => (),
+        }
+        BrowseAction::Continue
+    }
+    fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+    fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(self.matches.len().saturating_sub(1));
+    }
+    fn get_selected(&self) -> Option<&BrowsedNote> {
+        self.matches.get(self.selected).map(|&i| &self.notes[i])
+    }
+    /// Fuzzy matches the notes' names against the filter.
+    /// Notes stay grouped by periodical, better matches first.
+    fn update_matches(&mut self) {
+        let matcher = SkimMatcherV2::default().ignore_case();
+        let mut matches = self
+            .notes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, note)| {
+                let score = match self.filter.is_empty() {
+                    true => 0,
+                    false => matcher.fuzzy_match(&note.name, &self.filter)?,
+                };
+                Some((note.period, Reverse(score), i))
+            })
+            .collect::<Vec<_>>();
+        matches.sort();
+        self.matches = matches.into_iter().map(|(_, _, i)| i).collect();
+        self.selected = 0;
+    }
+    /// Draws the filter and the grouped notes on the left,
+    /// and the selected note's preview on the right.
+    pub fn draw(&self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(frame.area());
+        let [left, preview] =
+            Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)])
+                .areas(main);
+        let [filter, notes] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(left);
+
+        frame.render_widget(
+            Paragraph::new(format!("> {}", self.filter)).block(Block::bordered().title("Filter")),
+            filter,
+        );
+
+        // periodical headings are interleaved with the notes,
+        // so the selected row is tracked separately from the selected match
+        let mut items = vec![];
+        let mut selected_row = None;
+        let mut period = None;
+        self.matches.iter().enumerate().for_each(|(i, &note)| {
+            let note = &self.notes[note];
+            if period != Some(note.period) {
+                period = Some(note.period);
+                items.push(ListItem::new(note.period.to_string()).bold());
+            }
+            if i == self.selected {
+                selected_row = Some(items.len());
+            }
+            items.push(ListItem::new(format!("  {}", note.name)));
+        });
+        let title = format!("Notes {}/{}", self.matches.len(), self.notes.len());
+        let list = List::new(items)
+            .block(Block::bordered().title(title))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">");
+        frame.render_stateful_widget(
+            list,
+            notes,
+            &mut ListState::default().with_selected(selected_row),
+        );
+
+        let (title, contents) = match self.get_selected() {
+            // the note is read on every draw, so edits show up after the editor exits
+            Some(note) => (
+                note.name.as_str(),
+                std::fs::read_to_string(&note.path).unwrap_or_default(),
+            ),
+            None => ("Preview", String::new()),
+        };
+        let lines = contents.lines().map(style_markdown).collect::<Vec<_>>();
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(Block::bordered().title(title))
+                .wrap(Wrap { trim: false }),
+            preview,
+        );
+
+        frame.render_widget(
+            Line::from("type to filter | up/down select | enter open | esc clear/quit").dim(),
+            help,
+        );
+    }
+}
+
+/// Styles a line of markdown by its block syntax.
+/// Styles are only modifiers, so the preview reads in terminals without colors.
+fn style_markdown(line: &str) -> Line<'_> {
+    let trimmed = line.trim_start();
+    let style = match trimmed {
+        _ if trimmed.starts_with('#') => Style::new().add_modifier(Modifier::BOLD),
+        _ if trimmed.starts_with('>') => Style::new().add_modifier(Modifier::ITALIC),
+        _ if trimmed.starts_with("- [x]") => Style::new().add_modifier(Modifier::CROSSED_OUT),
+        _ if trimmed.starts_with("```") => Style::new().add_modifier(Modifier::DIM),
+        _ => Style::new(),
+    };
+    Line::styled(line, style)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{Terminal, backend::TestBackend};
+
+    use super::*;
+    use crate::vault::write_test_vault;
+
+    fn browser(files: &[(&str, &str)]) -> anyhow::Result<(tempfile::TempDir, Browser)> {
+        let vault = write_test_vault(files)?;
+        let config = AppConfig::builder(vault.path())
+            .dir(Periodical::Day, "day")
+            .try_build()?;
+        let browser = Browser::try_new(&config)?;
+        Ok((vault, browser))
+    }
+
+    fn press(browser: &mut Browser, keys: &[KeyCode]) -> BrowseAction {
+        keys.iter().fold(BrowseAction::Continue, |_, &code| {
+            browser.handle_key(KeyEvent::from(code))
+        })
+    }
+
+    #[test]
+    fn test_grouped_notes() -> anyhow::Result<()> {
+        let desc = "Test notes are grouped by periodical, newest first";
+        let (_vault, browser) = browser(&[
+            ("day/2025-12-30.md", ""),
+            ("day/2025-12-31.md", ""),
+            ("2026-W01.md", ""),
+            ("2025-12.md", ""),
+            ("inbox.md", ""),
+        ])?;
+        let got = browser
+            .matches
+            .iter()
+            .map(|&i| browser.notes[i].name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["2025-12-31", "2025-12-30", "2026-W01", "2025-12"],
+            got,
+            "{desc}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_keys() -> anyhow::Result<()> {
+        let desc = "Test keys filter, select and open notes";
+        let (_vault, mut browser) = browser(&[
+            ("day/2025-12-30.md", ""),
+            ("day/2025-12-31.md", ""),
+            ("day/2026-01-01.md", ""),
+            ("2026-W01.md", ""),
+        ])?;
+        let test_cases = [
+            (
+                vec![KeyCode::Down, KeyCode::Enter],
+                BrowseAction::Open(
+                    Periodical::Day,
+                    NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(),
+                ),
+            ),
+            (
+                vec![KeyCode::Up, KeyCode::Up, KeyCode::Enter],
+                BrowseAction::Open(
+                    Periodical::Day,
+                    NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                ),
+            ),
+            (
+                "w01"
+                    .chars()
+                    .map(KeyCode::Char)
+                    .chain([KeyCode::Enter])
+                    .collect(),
+                BrowseAction::Open(
+                    Periodical::Week,
+                    NaiveDate::from_ymd_opt(2025, 12, 29).unwrap(),
+                ),
+            ),
+            (vec![KeyCode::Esc], BrowseAction::Continue),
+            (vec![KeyCode::Esc], BrowseAction::Quit),
+        ];
+        test_cases.into_iter().for_each(|(keys, want)| {
+            let got = press(&mut browser, &keys);
+            assert_eq!(want, got, "{desc}: {keys:?}");
+        });
+
+        press(
+            &mut browser,
+            &"zzz".chars().map(KeyCode::Char).collect::<Vec<_>>(),
+        );
+        assert_eq!(0, browser.matches.len(), "{desc}: no matches");
+        assert_eq!(
+            BrowseAction::Continue,
+            press(&mut browser, &[KeyCode::Enter]),
+            "{desc}: open without matches"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_draw() -> anyhow::Result<()> {
+        let desc = "Test drawing the notes and the selected note's preview";
+        let (_vault, mut browser) = browser(&[
+            ("day/2025-12-30.md", "# Tuesday\n\n- [ ] write tests"),
+            ("2026-W01.md", "# Week one"),
+        ])?;
+        let mut terminal = Terminal::new(TestBackend::new(60, 12))?;
+        terminal.draw(|frame| browser.draw(frame))?;
+
+        let buffer = terminal.backend().buffer();
+        let got = buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>();
+        let screen = got.join("\n");
+        [
+            "Notes 2/2",
+            "day",
+            ">  2025-12-30",
+            "week",
+            "  2026-W01",
+            "# Tuesday",
+            "- [ ] write tests",
+        ]
+        .iter()
+        .for_each(|want| assert!(screen.contains(want), "{desc}: {want}\n{screen}"));
+
+        press(&mut browser, &[KeyCode::Down]);
+        terminal.draw(|frame| browser.draw(frame))?;
+        let buffer = terminal.backend().buffer();
+        let screen = buffer
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        assert!(screen.contains("# Week one"), "{desc}: selected preview");
+        assert!(!screen.contains("# Tuesday"), "{desc}: previous preview");
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt::Display;
+
+use chrono::{Datelike, Days, Months, NaiveDate};
+use serde::Serialize;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::Calendar;
+}
+
+/// Visible width of a rendered month: the week column and seven day columns.
+const WIDTH: usize = 3 + 7 * 4;
+/// Months rendered side by side in a year overview.
+const COLUMNS: usize = 3;
+/// Marks days and weeks that have a note, so the output reads without colors.
+const MARK: char = '*';
+const HIGHLIGHT: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+/// cal(1) style month grids, marking the days and weeks that have a note.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Calendar {
+    #[serde(skip)]
+    week_start: WeekStart,
+    months: Vec<CalendarMonth>,
+}
+
+/// A single month of the calendar.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct CalendarMonth {
+    /// First day of the month.
+    month: NaiveDate,
+    weeks: Vec<CalendarWeek>,
+}
+
+/// A row of the month grid, only holding the days within the month.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct CalendarWeek {
+    number: u32,
+    start: NaiveDate,
+    exists: bool,
+    days: Vec<CalendarDay>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct CalendarDay {
+    date: NaiveDate,
+    exists: bool,
+}
+
+impl Calendar {
+    /// Checks which notes exist in every month of the period the date falls in.
+    /// Only months and years are split into months, any other periodical
+    /// covers the month the date falls in.
+    pub fn try_new(
+        config: &AppConfig,
+        period: Periodical,
+        date: NaiveDate,
+    ) -> Result<Self, RuntimeError> {
+        let period = match period {
+            Periodical::Year => Periodical::Year,
+            _ => Periodical::Month,
+        };
+        let week_start = config.get_week_start();
+        let end = period.end_of(date, week_start);
+        let mut month = period.start_of(date, week_start);
+        let mut months = vec![];
+        while month <= end {
+            months.push(CalendarMonth::try_new(config, month)?);
+            month = month
+                .checked_add_months(Months::new(1))
+                .ok_or(RuntimeError::DateOutOfRange)?;
+        }
+        Ok(Self { week_start, months })
+    }
+    pub fn to_json(&self) -> Result<String, RuntimeError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+    /// Renders the months as text, side by side if there's more than one.
+    /// Days and weeks with a note are followed by a `*`, and also
+    /// shown in reverse video if colors are enabled.
+    pub fn render(&self, color: bool) -> String {
+        let blocks = self
+            .months
+            .iter()
+            .map(|month| month.render(self.week_start, color))
+            .collect::<Vec<_>>();
+        blocks
+            .chunks(COLUMNS)
+            .map(|row| {
+                let height = row.iter().map(|block| block.len()).max().unwrap_or(0);
+                (0..height)
+                    .map(|i| {
+                        let line = row
+                            .iter()
+                            .map(|block| block.get(i).cloned().unwrap_or(" ".repeat(WIDTH)))
+                            .collect::<Vec<_>>()
+                            .join("  ");
+                        line.trim_end().to_string()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl Display for Calendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(false))
+    }
+}
+
+impl CalendarMonth {
+    fn try_new(config: &AppConfig, month: NaiveDate) -> Result<Self, RuntimeError> {
+        let week_start = config.get_week_start();
+        let end = Periodical::Month.end_of(month, week_start);
+        let mut weeks = vec![];
+        let mut start = week_start.start_of(month);
+        while start <= end {
+            let days = (0..7)
+                .filter_map(|i| start.checked_add_days(Days::new(i)))
+                .filter(|date| (month..=end).contains(date))
+                .map(|date| {
+                    let exists = config
+                        .try_format_absolute_note_path(Periodical::Day, config.at_midnight(date))?
+                        .exists();
+                    Ok(CalendarDay { date, exists })
+                })
+                .collect::<Result<_, RuntimeError>>()?;
+            weeks.push(CalendarWeek {
+                number: config.get_week_numbering().number(start, week_start).1,
+                start,
+                exists: config
+                    .try_format_absolute_note_path(Periodical::Week, config.at_midnight(start))?
+                    .exists(),
+                days,
+            });
+            start = start
+                .checked_add_days(Days::new(7))
+                .ok_or(RuntimeError::DateOutOfRange)?;
+        }
+        Ok(Self { month, weeks })
+    }
+    /// Renders the title, the weekday header and the weeks, every line
+    /// padded to the same visible width.
+    fn render(&self, week_start: WeekStart, color: bool) -> Vec<String> {
+        let title = format!("{:^WIDTH$}", self.month.format("%B %Y").to_string());
+        let weekdays = (0..7)
+            .filter_map(|i| {
+                week_start
+                    .start_of(self.month)
+                    .checked_add_days(Days::new(i))
+            })
+            .map(|date| format!(" {} ", &date.weekday().to_string()[..2]))
+            .collect::<String>();
+        let mut lines = vec![title, format!("Wk {weekdays}")];
+
+        self.weeks.iter().for_each(|week| {
+            let mut line = mark(format!("{:>2}", week.number), week.exists, color);
+            let first = week
+                .days
+                .first()
+                .map_or(0, |day| week_start.days_since(day.date.weekday()) as usize);
+            line.push_str(&" ".repeat(first * 4));
+            week.days.iter().for_each(|day| {
+                line.push(' ');
+                line.push_str(&mark(format!("{:>2}", day.date.day()), day.exists, color));
+            });
+            line.push_str(&" ".repeat((7 - first - week.days.len()) * 4));
+            lines.push(line);
+        });
+        lines
+    }
+}
+
+/// Appends the mark to the cell if its note exists, or pads it to the same width.
+fn mark(cell: String, exists: bool, color: bool) -> String {
+    match (exists, color) {
+        (true, true) => format!("{HIGHLIGHT}{cell}{RESET}{MARK}"),
+        (true, false) => format!("{cell}{MARK}"),
+        (false, _) => format!("{cell} "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_month() -> anyhow::Result<()> {
+        let desc = "Test months render like cal, marking existing notes";
+        let vault = tempfile::tempdir()?;
+        std::fs::write(vault.path().join("2026-02-01.md"), "")?;
+        std::fs::write(vault.path().join("2026-02-14.md"), "")?;
+        std::fs::write(vault.path().join("2026-W07.md"), "")?;
+        let config = AppConfig::builder(vault.path()).try_build()?;
+        let date = NaiveDate::from_ymd_opt(2026, 2, 14).unwrap();
+
+        let got = Calendar::try_new(&config, Periodical::Month, date)?.to_string();
+        let want = [
+            "         February 2026",
+            "Wk  Mo  Tu  We  Th  Fr  Sa  Su",
+            " 5                           1*",
+            " 6   2   3   4   5   6   7   8",
+            " 7*  9  10  11  12  13  14* 15",
+            " 8  16  17  18  19  20  21  22",
+            " 9  23  24  25  26  27  28",
+        ]
+        .join("\n");
+        assert_eq!(want, got, "{desc}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_year() -> anyhow::Result<()> {
+        let desc = "Test years render three months side by side";
+        let vault = tempfile::tempdir()?;
+        let config = AppConfig::builder(vault.path())
+            .week_start(WeekStart::Sunday)
+            .try_build()?;
+        let date = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+
+        let calendar = Calendar::try_new(&config, Periodical::Year, date)?;
+        assert_eq!(12, calendar.months.len(), "{desc}: months");
+        let got = calendar.render(false);
+        let first = got.lines().take(2).collect::<Vec<_>>();
+        assert_eq!(
+            "         January 2026                     February 2026                     March 2026",
+            first[0],
+            "{desc}: titles"
+        );
+        assert_eq!(
+            ["Wk  Su  Mo  Tu  We  Th  Fr  Sa "; 3].join("  ").trim_end(),
+            first[1],
+            "{desc}: weekdays"
+        );
+        assert_eq!(4, got.split("\n\n").count(), "{desc}: rows\n{got}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_color() -> anyhow::Result<()> {
+        let desc = "Test colors highlight existing notes without changing the layout";
+        let vault = tempfile::tempdir()?;
+        std::fs::write(vault.path().join("2026-02-02.md"), "")?;
+        let config = AppConfig::builder(vault.path()).try_build()?;
+        let date = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+
+        let calendar = Calendar::try_new(&config, Periodical::Month, date)?;
+        let got = calendar.render(true);
+        assert!(
+            got.contains(&format!(" 6  {HIGHLIGHT} 2{RESET}*  3")),
+            "{desc}: {got}"
+        );
+        assert_eq!(
+            calendar.render(false),
+            got.replace(HIGHLIGHT, "").replace(RESET, ""),
+            "{desc}: layout"
+        );
+        Ok(())
+    }
+}
@@ -1,20 +1,47 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand, ValueEnum};
+use clap_complete::ArgValueCompleter;
 use strum::VariantNames;
 use strum_macros::Display;
 
 pub use crate::prelude::*;
 
 pub mod prelude {
-    pub use super::{Args, Commands};
+    pub use super::{Args, Command, Commands, ConfigCommands, NoteArgs};
 }
 
 #[derive(Debug, Parser)]
 #[command(version, about, long_about = None)]
 pub struct Args {
     #[command(subcommand)]
-    pub command: Option<Commands>,
+    pub command: Option<Command>,
+    /// Reports what would be written to the vault without writing anything
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+    /// Prints output and errors as JSON
+    #[arg(long, global = true)]
+    pub json: bool,
+}
+
+/// Every subcommand, with the ones that work without a loaded vault
+/// configuration split off from the vault's.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    #[command(flatten)]
+    Vault(Commands),
+    /// Inspects the app configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+    /// Prints the shell completion script for the passed in shell
+    #[clap(long_about = completions_help())]
+    Completions { shell: CompletionShell },
 }
 
+/// Subcommands run against the configured vault.
 #[derive(Debug, Display, Subcommand)]
 pub enum Commands {
     ///  Opens up passed in periodical note
@@ -26,6 +53,108 @@ pub enum Commands {
     /// Resets the app configuration to its default state
     #[clap(short_flag = 'r')]
     Reset,
+    /// Opens the following note of the same periodical
+    Next(NoteArgs),
+    /// Opens the preceding note of the same periodical
+    Prev(NoteArgs),
+    /// Opens the note of the next larger periodical containing the note
+    Up(NoteArgs),
+    /// Lists the notes of the next smaller periodical within the note's period
+    Down {
+        /// Path to the note, relative paths may start from the vault root
+        #[arg(add = ArgValueCompleter::new(complete_note))]
+        file: PathBuf,
+        /// Only lists notes that already exist
+        #[arg(long)]
+        existing: bool,
+    },
+    /// Prints the periodical and date a note belongs to
+    Which {
+        /// Path to the note, relative paths may start from the vault root
+        #[arg(add = ArgValueCompleter::new(complete_note))]
+        file: PathBuf,
+    },
+    /// Opens the note whose file name or alias best matches the query
+    Open {
+        /// Fuzzy query, asks which note to open if several match equally well
+        #[arg(required = true, add = ArgValueCompleter::new(complete_query))]
+        query: Vec<String>,
+    },
+    /// Lists the most recently opened notes, latest first
+    Recent {
+        /// Most notes listed
+        #[arg(long, short = 'n', default_value_t = 10)]
+        limit: usize,
+    },
+    /// Reopens the most recently opened note that still exists
+    Last,
+    /// Prints streaks and statistics of the periodical's notes and the vault
+    Stats {
+        #[arg(help = format!("{:?}", Periodical::VARIANTS))]
+        period: Option<Periodical>,
+    },
+    /// Exports the periodical's notes within a date range as a single document
+    Export {
+        #[arg(help = format!("{:?}", Periodical::VARIANTS))]
+        period: Periodical,
+        /// First date of the range, formatted as YYYY-MM-DD
+        #[arg(long)]
+        from: NaiveDate,
+        /// Last date of the range, formatted as YYYY-MM-DD, defaults to today
+        #[arg(long)]
+        to: Option<NaiveDate>,
+        /// Format of the exported document
+        #[arg(long, value_enum, default_value_t)]
+        format: ExportFormat,
+    },
+    /// Prints a note as a standalone HTML document, with wikilinks, embeds,
+    /// callouts and highlights rendered
+    Render {
+        /// Path to the note, relative paths may start from the vault root
+        #[arg(add = ArgValueCompleter::new(complete_note))]
+        file: PathBuf,
+    },
+    /// Browses the vault's periodical notes in an interactive terminal UI
+    Browse,
+    /// Prints a calendar of the month, marking days and weeks that have a note
+    Calendar {
+        /// Month to print, formatted as YYYY-MM, defaults to the current month
+        #[arg(long, value_parser = parse_month)]
+        month: Option<NaiveDate>,
+        /// Prints every month of the year instead
+        #[arg(long)]
+        year: bool,
+    },
+}
+
+/// Arguments of commands that navigate from an existing note.
+/// The target note is written if it doesn't exist.
+#[derive(Debug, clap::Args)]
+pub struct NoteArgs {
+    /// Path to the note, relative paths may start from the vault root
+    #[arg(add = ArgValueCompleter::new(complete_note))]
+    pub file: PathBuf,
+    /// Prints the target note's path instead of opening it
+    #[arg(long)]
+    pub print: bool,
+}
+
+#[derive(Debug, Display, Subcommand)]
+pub enum ConfigCommands {
+    /// Prints the fully resolved configuration, defaults included
+    Show {
+        /// Prints the configuration as TOML
+        #[arg(long, conflicts_with = "json")]
+        toml: bool,
+    },
+    /// Lists every location searched for a config file, marking the one in use
+    Path,
+}
+
+impl Default for Command {
+    fn default() -> Self {
+        Self::Vault(Commands::default())
+    }
 }
 
 impl Default for Commands {
@@ -42,3 +171,19 @@ fn periodical_help() -> String {
         Periodical::VARIANTS
     )
 }
+
+fn completions_help() -> String {
+    format!(
+        "Prints the shell completion script for the passed in shell\n\nSource the output on shell startup, e.g. `source <(sb completions bash)`.\nExcept for nushell, completions call back into sb and complete note names from the vault.\nArgument options are {:?}.",
+        CompletionShell::value_variants()
+            .iter()
+            .map(|shell| shell.to_string())
+            .collect::<Vec<_>>()
+    )
+}
+
+/// Parses a YYYY-MM month into its first day.
+fn parse_month(s: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(&format!("{s}-01"), "%Y-%m-%d")
+        .map_err(|_| format!("\"{s}\" isn't formatted as YYYY-MM"))
+}
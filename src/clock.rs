@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{Clock, FixedClock, NOW_ENV, SystemClock, try_clock_from_env};
+}
+
+/// Env variable that fixes the current time, formatted as `YYYY-MM-DDTHH:MM`
+/// in the configured timezone.
+pub const NOW_ENV: &str = "SB_NOW";
+/// Accepted formats of a fixed current time.
+const NOW_FMTS: [&str; 2] = ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S"];
+
+/// Source of the current time.
+pub trait Clock: Debug {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock that reads the system time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+/// Clock stopped at a fixed instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl FixedClock {
+    /// Attempts to parse a wall clock time, formatted as `YYYY-MM-DDTHH:MM[:SS]`,
+    /// in the configured timezone.
+    pub fn try_parse(s: &str, time: &TimeConfig) -> Result<Self, ConfigError> {
+        let local = NOW_FMTS
+            .iter()
+            .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+            .ok_or_else(|| ConfigError::Now(s.to_string()))?;
+        Ok(Self(time.at_local(local).to_utc()))
+    }
+}
+
+/// Returns a FixedClock if the env variable is set,
+/// and the system clock otherwise.
+pub fn try_clock_from_env(env: &str, time: &TimeConfig) -> Result<Box<dyn Clock>, ConfigError> {
+    match std::env::var(env) {
+        Ok(s) => Ok(Box::new(FixedClock::try_parse(&s, time)?)),
+        Err(_) => Ok(Box::new(SystemClock)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() -> anyhow::Result<()> {
+        let desc = "Test parsing fixed times in the configured timezone";
+        let test_cases = [
+            (
+                "timezone = \"UTC\"",
+                "2025-12-30T09:00",
+                Some("2025-12-30T09:00:00+00:00"),
+            ),
+            (
+                "timezone = \"Europe/Rome\"",
+                "2025-12-30T09:00:30",
+                Some("2025-12-30T08:00:30+00:00"),
+            ),
+            ("timezone = \"UTC\"", "2025-12-30", None),
+        ];
+        test_cases.iter().try_for_each(|(time, s, want)| {
+            let time = toml::from_str::<TimeConfig>(time)?;
+            let got = FixedClock::try_parse(s, &time).ok();
+            let got = got.map(|clock| clock.now().to_rfc3339());
+
+            assert_eq!(want.map(String::from), got, "{desc}: {s}");
+            anyhow::Ok(())
+        })
+    }
+
+    #[test]
+    fn test_iso_week_boundary() -> anyhow::Result<()> {
+        let desc = "Test a fixed clock reproduces dates across the ISO year";
+        let config = AppConfig::builder(".")
+            .timezone("UTC".parse().map_err(anyhow::Error::msg)?)
+            .try_build()?;
+        let clock = FixedClock::try_parse("2025-12-30T09:00", config.get_time_config())?;
+        let now = config.now(&clock);
+
+        assert_eq!(
+            "2026-W01",
+            config.format_date(Periodical::Week, now),
+            "{desc}"
+        );
+        assert_eq!("2025", config.format_date(Periodical::Year, now), "{desc}");
+        Ok(())
+    }
+}
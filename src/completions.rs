@@ -0,0 +1,168 @@
+use std::{ffi::OsStr, io::Write, path::PathBuf};
+
+use clap::{CommandFactory, ValueEnum};
+use clap_complete::{
+    engine::CompletionCandidate,
+    env::{Bash, Elvish, EnvCompleter, Fish, Zsh},
+};
+use clap_complete_nushell::Nushell;
+use strum_macros::Display;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{CompletionShell, complete_note, complete_query};
+}
+
+/// Env variable the registered shell scripts use to call back into `sb`
+/// when asking for completions.
+pub const COMPLETE_ENV: &str = "COMPLETE";
+
+/// Name of the binary the completions are registered for.
+const BIN: &str = "sb";
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    Elvish,
+    Nushell,
+}
+
+impl CompletionShell {
+    /// Writes the completion script for the shell.
+    /// Every shell but nushell registers dynamic completions that call back
+    /// into `sb`, so note names are read from the vault at completion time.
+    /// Nushell gets a static script generated from the command definitions.
+    pub fn write(&self, buf: &mut dyn Write) -> Result<(), RuntimeError> {
+        let completer: &dyn EnvCompleter = match self {
+            Self::Bash => &Bash,
+            Self::Zsh => &Zsh,
+            Self::Fish => &Fish,
+            Self::Elvish => &Elvish,
+            Self::Nushell => {
+                clap_complete::generate(Nushell, &mut Args::command(), BIN, buf);
+                return Ok(());
+            }
+        };
+        completer.write_registration(COMPLETE_ENV, BIN, BIN, BIN, buf)?;
+        Ok(())
+    }
+}
+
+/// Completes vault relative note paths for arguments that take a note.
+/// Returns no candidates if the configuration can't be loaded.
+pub fn complete_note(current: &OsStr) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    let notes = || -> Result<Vec<PathBuf>, Status> {
+        let config = try_load_config()?;
+        let root = config.get_vault_root();
+        let notes = list_notes(root)?
+            .into_iter()
+            .filter_map(|p| Some(p.strip_prefix(root).ok()?.to_path_buf()))
+            .collect();
+        Ok(notes)
+    };
+
+    notes()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|p| p.to_string_lossy().starts_with(&*current))
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Completes note file names and aliases for queries matching notes by name,
+/// case insensitively.
+/// Returns no candidates if the configuration can't be loaded.
+pub fn complete_query(current: &OsStr) -> Vec<CompletionCandidate> {
+    let names = || -> Result<Vec<String>, Status> {
+        let config = try_load_config()?;
+        Ok(note_names(&list_notes(config.get_vault_root())?))
+    };
+    let current = current.to_string_lossy().to_lowercase();
+    names()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|name| name.to_lowercase().starts_with(&current))
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Collects the file names, without extensions, and aliases of the notes,
+/// sorted and deduplicated. Notes that can't be read only add their file name.
+fn note_names(notes: &[PathBuf]) -> Vec<String> {
+    let mut names = notes
+        .iter()
+        .flat_map(|path| {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let contents = std::fs::read_to_string(path).unwrap_or_default();
+            let aliases = split_frontmatter(&contents)
+                .0
+                .map(parse_aliases)
+                .unwrap_or_default();
+            std::iter::once(stem.to_string()).chain(aliases)
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn try_load_config() -> Result<AppConfig, Status> {
+    let config_file = ConfigFile::try_from_env(CONFIG_ENV)?.try_build()?;
+    Ok(AppConfig::try_from(config_file)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::VariantNames;
+
+    use super::*;
+    use crate::vault::write_test_vault;
+
+    #[test]
+    fn test_write_scripts() -> anyhow::Result<()> {
+        let desc = "Test every shell gets a script registered for the sb binary";
+        CompletionShell::value_variants()
+            .iter()
+            .try_for_each(|shell| {
+                let mut buf = Vec::new();
+                shell.write(&mut buf)?;
+                let got = String::from_utf8(buf)?;
+
+                assert!(got.contains(BIN), "{desc}: {shell}");
+                anyhow::Ok(())
+            })
+    }
+
+    #[test]
+    fn test_note_names() -> anyhow::Result<()> {
+        let desc = "Test completing note file names and aliases";
+        let vault = write_test_vault(&[
+            ("weekly.md", "---\naliases: [Review, weekly]\n---\nbody"),
+            ("projects/garden.md", "no frontmatter"),
+        ])?;
+        assert_eq!(
+            vec!["Review", "garden", "weekly"],
+            note_names(&list_notes(vault.path())?),
+            "{desc}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_static_periodicals() -> anyhow::Result<()> {
+        let desc = "Test static scripts include the periodical variants";
+        let mut buf = Vec::new();
+        CompletionShell::Nushell.write(&mut buf)?;
+        let got = String::from_utf8(buf)?;
+
+        Periodical::VARIANTS
+            .iter()
+            .for_each(|variant| assert!(got.contains(variant), "{desc}: {variant}"));
+        Ok(())
+    }
+}
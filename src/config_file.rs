@@ -1,43 +1,159 @@
-use std::{env, path::PathBuf};
+use std::{
+    env,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
 
 use crate::prelude::*;
 
 pub mod prelude {
-    pub use super::ConfigFile;
+    pub use super::{ConfigFile, ConfigFileBuilder};
 }
 
+/// File name of the configuration inside of `$XDG_CONFIG_HOME/second-brain`.
+const XDG_FILE: &str = "config.toml";
+/// File name of the configuration inside of the OS config directory
+/// used before XDG discovery was supported.
+const LEGACY_FILE: &str = "sb_config.toml";
+/// File name of a configuration local to a directory tree.
+const LOCAL_FILE: &str = ".sb.toml";
+
 /// Struct that keeps track of the state of configuration I/O.
 /// This is newtype for the path buffer to the configuration file
 /// and ensures that the enclosed PathBuf has been validated.
 pub struct ConfigFile(pub PathBuf);
 
 /// Type state used to prevent AppConfig from consuming
-/// a non-inialized/invalid config file
-pub struct ConfigFileBuilder(PathBuf);
+/// a non-inialized/invalid config file.
+/// Holds every candidate location in order of precedence.
+pub struct ConfigFileBuilder(Vec<PathBuf>);
 
 impl ConfigFile {
     /// Attempts to initialize a ConfigFileBuilder.
-    /// If the env variable is not set, the program will provide
-    /// a default file path.
+    /// Candidates are searched in order:
+    /// the passed in env variable, `$XDG_CONFIG_HOME/second-brain/config.toml`,
+    /// the legacy `sb_config.toml` in the OS config directory,
+    /// and any `.sb.toml` found walking up from the current directory.
     pub fn try_from_env(s: &str) -> Result<ConfigFileBuilder, ConfigError> {
-        let path = match env::var(s) {
-            Ok(s) => PathBuf::from(s),
-            Err(_) => {
-                let root = dirs::config_dir().ok_or(ConfigError::SystemDir)?;
-                root.join("sb_config.toml")
-            }
-        };
-        Ok(ConfigFileBuilder(path))
+        let xdg_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|p| p.is_absolute())
+            .or_else(|| dirs::home_dir().map(|home| home.join(".config")));
+
+        Ok(ConfigFileBuilder(search_paths(
+            env::var_os(s).map(PathBuf::from),
+            xdg_home,
+            dirs::config_dir(),
+            &env::current_dir()?,
+        )))
     }
 }
 
 impl ConfigFileBuilder {
     /// Type conversion that implicitly validates the ConfigFileBuilder's
-    /// interior PathBuf
+    /// candidates, picking the first one that exists.
     pub fn try_build(self) -> Result<ConfigFile, ConfigError> {
-        if !self.0.is_file() {
-            return Err(ConfigError::InvalidFile(self.0));
+        match self.get_chosen() {
+            Some(path) => Ok(ConfigFile(path.to_owned())),
+            None => Err(ConfigError::InvalidFile(self.0)),
+        }
+    }
+    /// Getter for every candidate location in order of precedence.
+    pub fn get_candidates(&self) -> &[PathBuf] {
+        &self.0
+    }
+    /// Serializes every candidate and the chosen one as a JSON object.
+    pub fn to_json(&self) -> Result<String, RuntimeError> {
+        let json = serde_json::json!({
+            "candidates": self.get_candidates(),
+            "chosen": self.get_chosen(),
+        });
+        Ok(serde_json::to_string(&json)?)
+    }
+    /// Getter for the first candidate that is an existing file.
+    pub fn get_chosen(&self) -> Option<&Path> {
+        self.0.iter().find(|p| p.is_file()).map(|p| p.as_path())
+    }
+}
+
+impl Display for ConfigFileBuilder {
+    /// Lists every candidate, marking the chosen one with an asterisk.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let chosen = self.get_chosen();
+        let lines = self
+            .0
+            .iter()
+            .map(|p| match Some(p.as_path()) == chosen {
+                true => format!("* {}", p.display()),
+                false => format!("  {}", p.display()),
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Builds the ordered list of configuration file candidates.
+/// Directories that couldn't be determined are skipped.
+fn search_paths(
+    env_path: Option<PathBuf>,
+    xdg_home: Option<PathBuf>,
+    config_dir: Option<PathBuf>,
+    cwd: &Path,
+) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    paths.extend(env_path);
+    paths.extend(xdg_home.map(|p| p.join("second-brain").join(XDG_FILE)));
+    paths.extend(config_dir.map(|p| p.join(LEGACY_FILE)));
+    paths.extend(cwd.ancestors().map(|p| p.join(LOCAL_FILE)));
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_paths() {
+        let desc = "Test candidate precedence";
+        let got = search_paths(
+            Some("/env/sb.toml".into()),
+            Some("/home/.config".into()),
+            Some("/os/config".into()),
+            Path::new("/vaults/notes"),
+        );
+        let want = [
+            "/env/sb.toml",
+            "/home/.config/second-brain/config.toml",
+            "/os/config/sb_config.toml",
+            "/vaults/notes/.sb.toml",
+            "/vaults/.sb.toml",
+            "/.sb.toml",
+        ]
+        .map(PathBuf::from);
+
+        assert_eq!(want.as_slice(), got, "{desc}");
+    }
+
+    #[test]
+    fn test_search_paths_unset() {
+        let desc = "Test unset locations are skipped";
+        let got = search_paths(None, None, None, Path::new("/"));
+
+        assert_eq!(vec![PathBuf::from("/.sb.toml")], got, "{desc}");
+    }
+
+    #[test]
+    fn test_invalid_candidates() {
+        let desc = "Test error lists every tried location";
+        let paths = ["/invalid/a.toml", "/invalid/b.toml"].map(PathBuf::from);
+        let builder = ConfigFileBuilder(paths.to_vec());
+
+        assert!(builder.get_chosen().is_none(), "{desc}");
+        match builder.try_build() {
+            Ok(_) => panic!("{desc}: expected error"),
+            Err(e) => paths
+                .iter()
+                .for_each(|p| assert!(e.to_string().contains(&*p.to_string_lossy()), "{desc}")),
         }
-        Ok(ConfigFile(self.0))
     }
 }
@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use regex::Regex;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::parse_date_fields;
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Date fields captured from a string formatted with a strftime-like format.
+#[derive(Debug, Default, PartialEq)]
+pub struct DateFields {
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+    pub ordinal: Option<u32>,
+    pub week: Option<u32>,
+}
+
+impl DateFields {
+    /// Attempts to build a date out of the captured year, month, day
+    /// or day of the year.
+    /// Missing months and days default to the first of the year/month.
+    pub fn to_date(&self) -> Option<NaiveDate> {
+        let year = self.year?;
+        match self.ordinal {
+            Some(ordinal) => NaiveDate::from_yo_opt(year, ordinal),
+            None => NaiveDate::from_ymd_opt(year, self.month.unwrap_or(1), self.day.unwrap_or(1)),
+        }
+    }
+}
+
+/// Attempts to capture the date fields of a string formatted with the
+/// passed in format. Inverse of chrono's formatting with the `{start}`,
+/// `{end}` and `{week_of_month}` tokens of file name formats.
+/// Only the first occurrence of a field is captured, and only `{start}`
+/// tokens capture fields.
+/// Returns None if the string doesn't match the format.
+pub fn parse_date_fields(fmt: &str, s: &str) -> Option<DateFields> {
+    let mut pattern = String::from("^");
+    build_pattern(fmt, true, &mut HashSet::new(), &mut pattern);
+    pattern.push('$');
+
+    let captures = Regex::new(&pattern).ok()?.captures(s)?;
+    let number = |name: &str| captures.name(name)?.as_str().trim().parse::<i64>().ok();
+    let month_name = |name: &str| {
+        let name = captures.name(name)?.as_str().to_lowercase();
+        let i = MONTHS
+            .iter()
+            .position(|month| month.to_lowercase().starts_with(&name))?;
+        Some(i as u32 + 1)
+    };
+
+    let short_year = number("short_year").map(|y| match y < 70 {
+        true => 2000 + y,
+        false => 1900 + y,
+    });
+    Some(DateFields {
+        year: number("year").or(short_year).map(|y| y as i32),
+        month: number("month")
+            .map(|m| m as u32)
+            .or_else(|| month_name("month_name")),
+        day: number("day").map(|d| d as u32),
+        ordinal: number("ordinal").map(|o| o as u32),
+        week: number("week").map(|w| w as u32),
+    })
+}
+
+/// Appends the regex pattern matching the format to `out`.
+/// Fields already in `captured`, or all fields if `capture` is false,
+/// are matched without capturing.
+fn build_pattern(fmt: &str, capture: bool, captured: &mut HashSet<&'static str>, out: &mut String) {
+    let mut rest = fmt;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '{' {
+            if let Some(after) = rest.strip_prefix("{week_of_month}") {
+                out.push_str(r"\d+");
+                rest = after;
+                continue;
+            }
+            if let Some((token_fmt, after)) = date_token(rest, "start") {
+                build_pattern(token_fmt, capture, captured, out);
+                rest = after;
+                continue;
+            }
+            if let Some((token_fmt, after)) = date_token(rest, "end") {
+                build_pattern(token_fmt, false, captured, out);
+                rest = after;
+                continue;
+            }
+        }
+        if c != '%' {
+            out.push_str(&regex::escape(&c.to_string()));
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        // strftime specifier with an optional padding modifier
+        let mut chars = rest[1..].chars();
+        let (pad, spec) = match chars.next() {
+            Some(pad @ ('-' | '_' | '0')) => (Some(pad), chars.next()),
+            spec => (None, spec),
+        };
+        let numeric = |width: usize, default_pad: char| match pad.unwrap_or(default_pad) {
+            '-' => format!(r"\d{{1,{width}}}"),
+            '_' => format!(r" *\d{{1,{width}}}"),
+            _ => format!(r"\d{{{width}}}"),
+        };
+        let field = match spec {
+            Some('Y' | 'G') => Some(("year", r"[+-]?\d{4,}".to_string())),
+            Some('y' | 'g') => Some(("short_year", numeric(2, '0'))),
+            Some('m') => Some(("month", numeric(2, '0'))),
+            Some('d') => Some(("day", numeric(2, '0'))),
+            Some('e') => Some(("day", numeric(2, '_'))),
+            Some('j') => Some(("ordinal", numeric(3, '0'))),
+            Some('V') => Some(("week", numeric(2, '0'))),
+            Some('B') => Some(("month_name", MONTHS.join("|"))),
+            Some('b' | 'h') => Some(("month_name", MONTHS.map(|m| &m[..3]).join("|"))),
+            Some(spec) => {
+                match spec {
+                    'F' => build_pattern("%Y-%m-%d", capture, captured, out),
+                    'D' => build_pattern("%m/%d/%y", capture, captured, out),
+                    '%' => out.push('%'),
+                    'n' => out.push('\n'),
+                    't' => out.push('\t'),
+                    // fields that can't be inverted are validated by formatting again
+                    _ => out.push_str(".+?"),
+                }
+                None
+            }
+            None => {
+                out.push_str(&regex::escape("%"));
+                None
+            }
+        };
+        if let Some((name, pattern)) = field {
+            match capture && captured.insert(name) {
+                true => out.push_str(&format!("(?P<{name}>{pattern})")),
+                false => out.push_str(&format!("(?:{pattern})")),
+            };
+        }
+        let len = 1 + pad.map_or(0, |_| 1) + spec.map_or(0, |c| c.len_utf8());
+        rest = &rest[len.min(rest.len())..];
+    }
+}
+
+/// Splits a leading `{name}` or `{name:fmt}` token off of the string.
+/// Returns the token's date format and the rest of the string.
+fn date_token<'a>(s: &'a str, name: &str) -> Option<(&'a str, &'a str)> {
+    let token = s.strip_prefix('{')?.strip_prefix(name)?;
+    let (inner, rest) = token.split_once('}')?;
+    match inner {
+        "" => Some((DEFAULT_DAY, rest)),
+        _ => Some((inner.strip_prefix(':')?, rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fields() {
+        let desc = "Test capturing date fields from formatted strings";
+        let test_cases = [
+            (
+                "%Y-%m-%d",
+                "2025-12-30",
+                Some((2025, 12, 30)),
+                "Case default day",
+            ),
+            (
+                "%m-%d-%Y",
+                "12-30-2025",
+                Some((2025, 12, 30)),
+                "Case reordered",
+            ),
+            (
+                "%d %B %y",
+                "30 December 25",
+                Some((2025, 12, 30)),
+                "Case names",
+            ),
+            (
+                "%-d %b %Y",
+                "3 Dec 2025",
+                Some((2025, 12, 3)),
+                "Case unpadded",
+            ),
+            (
+                "%F (%A)",
+                "2025-12-30 (Tuesday)",
+                Some((2025, 12, 30)),
+                "Case weekday",
+            ),
+            (
+                "{start} to {end}",
+                "2025-12-29 to 2026-01-04",
+                Some((2025, 12, 29)),
+                "Case tokens",
+            ),
+            ("%Y-%m", "2025-12", Some((2025, 12, 1)), "Case month"),
+            ("%Y-%m-%d", "2025-12", None, "Case mismatch"),
+        ];
+        test_cases.into_iter().for_each(|(fmt, s, want, case)| {
+            let want = want.map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap());
+            let got = parse_date_fields(fmt, s).and_then(|f| f.to_date());
+            assert_eq!(want, got, "{desc}: {case}");
+        });
+    }
+
+    #[test]
+    fn test_parse_week_fields() {
+        let desc = "Test capturing week numbers";
+        let got = parse_date_fields("%Y-W%V", "2026-W01");
+        let want = DateFields {
+            year: Some(2026),
+            week: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(Some(want), got, "{desc}");
+    }
+}
@@ -0,0 +1,261 @@
+use std::{
+    fmt::Display,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{prelude::*, time_config::DayStart};
+
+pub mod prelude {
+    pub use super::Diagnostic;
+}
+
+/// Part of a config that commonly fails to deserialize,
+/// each with a hint on fixing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hint {
+    MissingVault,
+    PeriodicalKey,
+    WeekStart,
+    WeekNumbering,
+    DayStart,
+    Timezone,
+}
+
+impl Hint {
+    /// Finds the part of the config source that fails to deserialize
+    /// by deserializing each part into its own type.
+    /// Returns None for TOML syntax errors and unhinted parts.
+    fn find(source: &str) -> Option<Self> {
+        let table = source.parse::<toml::Table>().ok()?;
+        if !table.contains_key("vault") {
+            return Some(Self::MissingVault);
+        }
+        let periodicals = table.get("periodical").and_then(toml::Value::as_table);
+        for (key, config) in periodicals.into_iter().flatten() {
+            if fails::<Periodical>(Some(&toml::Value::String(key.clone()))) {
+                return Some(Self::PeriodicalKey);
+            }
+            if fails::<WeekStart>(config.get("week_start")) {
+                return Some(Self::WeekStart);
+            }
+            if fails::<WeekNumbering>(config.get("week_numbering")) {
+                return Some(Self::WeekNumbering);
+            }
+        }
+        let time = table.get("time");
+        if fails::<DayStart>(time.and_then(|t| t.get("day_starts_at"))) {
+            return Some(Self::DayStart);
+        }
+        if fails::<Timezone>(time.and_then(|t| t.get("timezone"))) {
+            return Some(Self::Timezone);
+        }
+        None
+    }
+    fn message(self) -> &'static str {
+        match self {
+            Self::MissingVault => "every config needs a [vault] table with a dir",
+            Self::PeriodicalKey => "periodical key must be one of day/week/month/year",
+            Self::WeekStart => "week_start must be monday or sunday",
+            Self::WeekNumbering => "week_numbering must be one of iso/us/simple",
+            Self::DayStart => "day_starts_at takes a 24 hour time, like \"04:00\"",
+            Self::Timezone => {
+                "timezone takes a name like \"Europe/Rome\" or an offset like \"+02:00\""
+            }
+        }
+    }
+}
+
+/// Whether the value is set but doesn't deserialize into the type.
+fn fails<T: DeserializeOwned>(value: Option<&toml::Value>) -> bool {
+    value.is_some_and(|v| v.clone().try_into::<T>().is_err())
+}
+
+/// Error in a config file, pointing at the offending TOML.
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    message: String,
+    path: PathBuf,
+    /// Line number, starting at 1, and the line's contents.
+    line: Option<(usize, String)>,
+    /// Columns of the line to underline, starting at 0.
+    columns: Range<usize>,
+    hint: Option<String>,
+}
+
+impl Diagnostic {
+    /// Points a message at the span of the config file's source.
+    pub fn new(
+        message: impl Into<String>,
+        path: &Path,
+        source: &str,
+        span: Option<Range<usize>>,
+    ) -> Self {
+        let mut diagnostic = Self {
+            message: message.into(),
+            path: path.to_path_buf(),
+            line: None,
+            columns: 0..0,
+            hint: None,
+        };
+        let Some(span) = span else {
+            return diagnostic;
+        };
+        let start = span.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let line = &source[line_start..line_end];
+        let column = source[line_start..start].chars().count();
+        // spans over multiple lines are underlined up to the end of the first
+        let len = source[start..span.end.clamp(start, line_end)]
+            .chars()
+            .count();
+
+        diagnostic.line = Some((
+            source[..line_start].matches('\n').count() + 1,
+            line.to_string(),
+        ));
+        diagnostic.columns = column..column + len.max(1);
+        diagnostic
+    }
+    /// Converts a deserialization error, adding a hint for common mistakes.
+    pub fn from_toml(e: &toml::de::Error, path: &Path, source: &str) -> Self {
+        let hint = Hint::find(source).map(|hint| hint.message().to_string());
+        Self {
+            hint,
+            ..Self::new(e.message().trim(), path, source, e.span())
+        }
+    }
+    /// Getter for the error message, without the snippet or hint.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    /// Getter for the config file the diagnostic points into.
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.message)?;
+        let Some((number, line)) = &self.line else {
+            write!(f, "  --> {}", self.path.display())?;
+            if let Some(hint) = &self.hint {
+                write!(f, "\n  = hint: {hint}")?;
+            }
+            return Ok(());
+        };
+        let gutter = " ".repeat(number.to_string().len());
+        writeln!(
+            f,
+            "{gutter}--> {}:{number}:{}",
+            self.path.display(),
+            self.columns.start + 1
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {line}")?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.columns.start),
+            "^".repeat(self.columns.len())
+        )?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n{gutter} = hint: {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_diagnostic() {
+        let desc = "Test deserialization errors point at the TOML";
+        let source = "[vault]\ndir = \".\"\n\n[periodical.days]\ndir = \"day\"\n";
+        let e = toml::from_str::<crate::app_config::TomlConfig>(source).unwrap_err();
+        let got = Diagnostic::from_toml(&e, Path::new("/config.toml"), source).to_string();
+        let want = [
+            "unknown variant `days`, expected one of `day`, `week`, `month`, `year`",
+            " --> /config.toml:4:13",
+            "  |",
+            "4 | [periodical.days]",
+            "  |             ^^^^",
+            "  = hint: periodical key must be one of day/week/month/year",
+        ]
+        .join("\n");
+        assert_eq!(want, got, "{desc}");
+    }
+
+    #[test]
+    fn test_hints() {
+        let desc = "Test hints follow the part of the config that fails";
+        let test_cases = [
+            ("[periodical.day]\ndir = \"day\"", Some(Hint::MissingVault)),
+            (
+                "[vault]\ndir = \".\"\n[periodical.days]",
+                Some(Hint::PeriodicalKey),
+            ),
+            (
+                "[vault]\ndir = \".\"\n[periodical.week]\nweek_start = \"friday\"",
+                Some(Hint::WeekStart),
+            ),
+            (
+                "[vault]\ndir = \".\"\n[periodical.week]\nweek_numbering = \"odd\"",
+                Some(Hint::WeekNumbering),
+            ),
+            (
+                "[vault]\ndir = \".\"\n[time]\nday_starts_at = \"4am\"",
+                Some(Hint::DayStart),
+            ),
+            (
+                "[vault]\ndir = \".\"\n[time]\ntimezone = \"Mars/Olympus\"",
+                Some(Hint::Timezone),
+            ),
+            (
+                "[vault]\ndir = \".\"\n[time]\ntimezone = 2",
+                Some(Hint::Timezone),
+            ),
+            ("[vault]\ndir = \".\"\n[git]\nauto_commit = 1", None),
+            ("[vault\ndir = \".\"", None),
+        ];
+        test_cases.into_iter().for_each(|(source, want)| {
+            assert_eq!(want, Hint::find(source), "{desc}: {source}");
+        });
+    }
+
+    #[test]
+    fn test_span_columns() {
+        let desc = "Test spans are converted to lines and columns";
+        let source = "a = 1\nbb = \"two\"\nc = 3";
+        let test_cases = [
+            (0..1, (1, "a = 1"), 0..1),
+            (11..16, (2, "bb = \"two\""), 5..10),
+            // multi line spans stop at the end of the first line
+            (6..17, (2, "bb = \"two\""), 0..10),
+            (17..17, (3, "c = 3"), 0..1),
+        ];
+        test_cases
+            .into_iter()
+            .for_each(|(span, (number, line), want)| {
+                let got = Diagnostic::new("", Path::new(""), source, Some(span.clone()));
+                assert_eq!(
+                    Some((number, line.to_string())),
+                    got.line,
+                    "{desc}: {span:?}"
+                );
+                assert_eq!(want, got.columns, "{desc}: {span:?}");
+            });
+    }
+}
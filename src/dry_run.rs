@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub(crate) use super::DryRunReport;
+}
+
+/// Describes a change that was skipped because of a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "kebab-case")]
+pub(crate) enum DryRunReport {
+    /// A note and the directories missing above it would have been written.
+    CreateNote {
+        path: PathBuf,
+        directories: Vec<PathBuf>,
+        contents: String,
+    },
+    /// A note would have been opened in the editor.
+    OpenNote { path: PathBuf },
+}
+
+impl DryRunReport {
+    /// Describes writing the note, creating the directories missing above it.
+    pub(crate) fn create_note(path: &Path, contents: &[u8]) -> Self {
+        Self::CreateNote {
+            path: path.to_path_buf(),
+            directories: missing_dirs(path),
+            contents: String::from_utf8_lossy(contents).into_owned(),
+        }
+    }
+    /// Describes opening the note in the editor.
+    pub(crate) fn open_note(path: &Path) -> Self {
+        Self::OpenNote {
+            path: path.to_path_buf(),
+        }
+    }
+    /// Prints the report, as a line of JSON if the run asks for it.
+    pub(crate) fn print(&self, options: &RunOptions) -> Result<(), RuntimeError> {
+        match options.is_json() {
+            true => println!("{}", self.to_json()?),
+            false => print!("{}", self.to_text()),
+        }
+        Ok(())
+    }
+    fn to_json(&self) -> Result<String, RuntimeError> {
+        serde_json::to_string(self).map_err(RuntimeError::Json)
+    }
+    fn to_text(&self) -> String {
+        match self {
+            Self::CreateNote {
+                path,
+                directories,
+                contents,
+            } => {
+                let mut report = format!("Would create note: {}\n", path.display());
+                directories.iter().for_each(|dir| {
+                    report.push_str(&format!("Would create directory: {}\n", dir.display()))
+                });
+                report.push_str("Contents:\n");
+                report.push_str(contents);
+                if !report.ends_with('\n') {
+                    report.push('\n');
+                }
+                report
+            }
+            Self::OpenNote { path } => format!("Would open note: {}\n", path.display()),
+        }
+    }
+}
+
+/// Lists the ancestors of the path that don't exist yet, outermost first.
+fn missing_dirs(path: &Path) -> Vec<PathBuf> {
+    let mut dirs = path
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+        .map(Path::to_path_buf)
+        .collect::<Vec<_>>();
+    dirs.reverse();
+    dirs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reports() -> anyhow::Result<()> {
+        let desc = "Test dry run reports as text and JSON";
+        let vault = tempfile::tempdir()?;
+        let path = vault.path().join("journal/day/2025-12-30.md");
+        let test_cases = [
+            (
+                DryRunReport::create_note(&path, b"# Today"),
+                [
+                    format!("Would create note: {}", path.display()),
+                    format!(
+                        "Would create directory: {}",
+                        vault.path().join("journal").display()
+                    ),
+                    format!(
+                        "Would create directory: {}",
+                        vault.path().join("journal/day").display()
+                    ),
+                    "Contents:".to_string(),
+                    "# Today".to_string(),
+                ]
+                .join("\n")
+                    + "\n",
+                serde_json::json!({
+                    "action": "create-note",
+                    "path": path,
+                    "directories": [vault.path().join("journal"), vault.path().join("journal/day")],
+                    "contents": "# Today",
+                }),
+            ),
+            (
+                DryRunReport::open_note(&path),
+                format!("Would open note: {}\n", path.display()),
+                serde_json::json!({"action": "open-note", "path": path}),
+            ),
+        ];
+        test_cases.into_iter().try_for_each(|(report, text, json)| {
+            assert_eq!(text, report.to_text(), "{desc}: {report:?}");
+            let got: serde_json::Value = serde_json::from_str(&report.to_json()?)?;
+            assert_eq!(json, got, "{desc}: {report:?}");
+            anyhow::Ok(())
+        })
+    }
+}
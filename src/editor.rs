@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::open_in_editor;
+}
+
+/// Opens the note in `$EDITOR`, falling back to `nvim`.
+/// The editor is started from the vault root, so vault relative
+/// links resolve from inside the editor.
+pub fn open_in_editor(config: &AppConfig, path: &Path) -> Result<(), RuntimeError> {
+    let editor = std::env::var("EDITOR").unwrap_or("nvim".into());
+    std::env::set_current_dir(config.get_vault_root())?;
+    std::process::Command::new(editor).arg(path).status()?;
+
+    Ok(())
+}
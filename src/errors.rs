@@ -1,10 +1,20 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use strum_macros::IntoStaticStr;
+
+use crate::prelude::*;
 
 pub mod prelude {
-    pub use super::{ConfigError, RuntimeError, Status};
+    pub use super::{CONFIG_EXIT_CODE, ConfigError, RUNTIME_EXIT_CODE, RuntimeError, Status};
 }
 
-#[derive(Debug, thiserror::Error)]
+/// Exit code of failures at runtime.
+pub const RUNTIME_EXIT_CODE: u8 = 1;
+/// Exit code of invalid configurations, `EX_CONFIG` of sysexits.h.
+pub const CONFIG_EXIT_CODE: u8 = 78;
+
+#[derive(Debug, thiserror::Error, IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
 /// Top level error that converts, reformats, and handles any and all errors.
 pub enum Status {
     #[error("Config error: {0}")]
@@ -13,22 +23,131 @@ pub enum Status {
     RuntimeError(#[from] RuntimeError),
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, thiserror::Error, IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
 pub enum RuntimeError {
     #[error("IO issue reading or writing file: {0}")]
     Io(#[from] std::io::Error),
+    #[error("{0} isn't a periodical note")]
+    NotPeriodical(PathBuf),
+    #[error("{0} notes have no larger periodical")]
+    NoParent(Periodical),
+    #[error("{0} notes have no smaller periodical")]
+    NoChildren(Periodical),
+    #[error("No notes have been opened yet")]
+    NoHistory,
+    #[error("No note matches \"{0}\"")]
+    NoMatch(String),
+    #[error("Several notes are named \"{query}\":{}", list_paths(notes))]
+    Ambiguous { query: String, notes: Vec<PathBuf> },
+    #[error("Date is out of the supported range")]
+    DateOutOfRange,
+    #[error("Couldn't commit to the vault's git repository: {0}")]
+    Git(#[from] git2::Error),
+    #[error("The {hook} hook failed:\n{stderr}")]
+    Hook { hook: Hook, stderr: String },
+    #[error("Couldn't serialize output to JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Couldn't serialize output to TOML: {0}")]
+    Toml(#[from] toml::ser::Error),
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, thiserror::Error, IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
 pub enum ConfigError {
-    #[error("Issue with deserialization: {0}")]
-    De(#[from] toml::de::Error),
+    #[error("{0}")]
+    #[strum(serialize = "invalid-config")]
+    Diagnostic(Box<Diagnostic>),
     #[error("Passed in path: {0} doesn't exist or isn't a directory")]
     InvalidDir(PathBuf),
-    #[error("Passed in path: {0} doesn't exist or isn't a file")]
-    InvalidFile(PathBuf),
+    #[error("No valid file found, tried:{}", list_paths(.0))]
+    InvalidFile(Vec<PathBuf>),
     #[error("Couldn't read file: {0}.")]
     Io(#[from] std::io::Error),
     #[error("Couldn't parse system's OS config directory.")]
     SystemDir,
+    #[error(
+        "Couldn't find the state directory the history is kept in, set $XDG_STATE_HOME or $HOME."
+    )]
+    StateDir,
+    #[error("State directory {0} from $XDG_STATE_HOME isn't an absolute path to a directory.")]
+    InvalidStateDir(PathBuf),
+    #[error("{NOW_ENV} \"{0}\" isn't formatted as YYYY-MM-DDTHH:MM")]
+    Now(String),
+}
+
+impl From<Diagnostic> for ConfigError {
+    fn from(value: Diagnostic) -> Self {
+        Self::Diagnostic(Box::new(value))
+    }
+}
+
+impl Status {
+    /// Exit code of the process, so scripts can tell failure types apart.
+    /// Command line usage errors exit with 2.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Status::ConfigError(_) => CONFIG_EXIT_CODE,
+            Status::RuntimeError(_) => RUNTIME_EXIT_CODE,
+        }
+    }
+    /// Kebab-case name of the error variant, e.g. `invalid-dir`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Status::ConfigError(e) => e.into(),
+            Status::RuntimeError(e) => e.into(),
+        }
+    }
+    /// Path of the file or directory the error is about, if any.
+    /// Of the config files searched for, the first candidate.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Status::ConfigError(ConfigError::InvalidDir(path)) => Some(path),
+            Status::ConfigError(ConfigError::InvalidStateDir(path)) => Some(path),
+            Status::ConfigError(ConfigError::InvalidFile(paths)) => {
+                paths.first().map(|p| p.as_path())
+            }
+            Status::ConfigError(ConfigError::Diagnostic(diagnostic)) => Some(diagnostic.get_path()),
+            Status::RuntimeError(RuntimeError::NotPeriodical(path)) => Some(path),
+            _ => None,
+        }
+    }
+    /// Every path the error is about, like all the config files searched for
+    /// or the notes a query matches.
+    pub fn paths(&self) -> Vec<&Path> {
+        match self {
+            Status::ConfigError(ConfigError::InvalidFile(paths))
+            | Status::RuntimeError(RuntimeError::Ambiguous { notes: paths, .. }) => {
+                paths.iter().map(|p| p.as_path()).collect()
+            }
+            _ => self.path().into_iter().collect(),
+        }
+    }
+    /// Serializes the error as a JSON object with its status, kind, message and paths.
+    pub fn to_json(&self) -> String {
+        let (status, message) = match self {
+            // the snippet is left out, the path and message locate the error
+            Status::ConfigError(ConfigError::Diagnostic(diagnostic)) => {
+                ("config", diagnostic.get_message().to_string())
+            }
+            Status::ConfigError(e) => ("config", e.to_string()),
+            Status::RuntimeError(e) => ("runtime", e.to_string()),
+        };
+        serde_json::json!({
+            "status": status,
+            "kind": self.kind(),
+            "message": message,
+            "path": self.path(),
+            "paths": self.paths(),
+        })
+        .to_string()
+    }
+}
+
+/// Formats a list of paths as an indented list, one path per line.
+fn list_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| format!("\n  {}", p.display()))
+        .collect()
 }
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use clap::ValueEnum;
+use strum_macros::Display;
+
+use crate::{prelude::*, render::slugify};
+
+pub mod prelude {
+    pub use super::{Export, ExportFormat};
+}
+
+/// Document format of an export.
+#[derive(Debug, Default, Display, Clone, Copy, PartialEq, ValueEnum)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ExportFormat {
+    #[default]
+    Md,
+    Html,
+}
+
+/// Notes of a periodical within a date range, stitched into a single document.
+#[derive(Debug, PartialEq)]
+pub struct Export {
+    from: NaiveDate,
+    to: NaiveDate,
+    notes: Vec<ExportedNote>,
+    /// Renders HTML exports, with links to exported notes pointing at their anchors.
+    renderer: Renderer,
+}
+
+#[derive(Debug, PartialEq)]
+struct ExportedNote {
+    /// File name without the extension, which wikilinks point at.
+    name: String,
+    /// Heading built from the note's date.
+    title: String,
+    /// Contents without frontmatter or the generated navigation heading.
+    body: String,
+}
+
+impl Export {
+    /// Attempts to read every existing note of the periodical
+    /// whose period overlaps `from..=to`, in order.
+    pub fn try_new(
+        config: &AppConfig,
+        period: Periodical,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Self, RuntimeError> {
+        let mut notes = vec![];
+        let mut date = Some(config.at_midnight(period.start_of(from, config.get_week_start())));
+        while let Some(day) = date.filter(|date| date.date_naive() <= to) {
+            let path = config.try_format_absolute_note_path(period, day)?;
+            if path.is_file() {
+                let contents = std::fs::read_to_string(&path)?;
+                let (_, body) = split_frontmatter(&contents);
+                notes.push(ExportedNote {
+                    name: config.format_date(period, day),
+                    title: format_title(config, period, day.date_naive()),
+                    body: strip_navigation(body).trim().to_string(),
+                });
+            }
+            date = period.get_next(day, 1);
+        }
+        let renderer = Renderer::try_new(config)?.with_anchors(
+            notes
+                .iter()
+                .map(|note| (note.name.clone(), slugify(&note.name))),
+        );
+        Ok(Self {
+            from,
+            to,
+            notes,
+            renderer,
+        })
+    }
+    /// Renders the export in the format.
+    pub fn render(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Md => self.to_markdown(),
+            ExportFormat::Html => self.to_html(),
+        }
+    }
+    /// Renders the notes as markdown, each under a heading anchored at its name.
+    /// Wikilinks to exported notes point at their anchors instead.
+    pub fn to_markdown(&self) -> String {
+        let anchors = self
+            .notes
+            .iter()
+            .map(|note| (note.name.as_str(), slugify(&note.name)))
+            .collect::<HashMap<_, _>>();
+
+        let mut document = format!("# Notes from {} to {}\n", self.from, self.to);
+        for note in &self.notes {
+            let body = replace_wikilinks(&note.body, |link| {
+                let anchor = anchors.get(link.get_note_name()).filter(|_| !link.embed)?;
+                Some(format!("[{}](#{anchor})", link.get_text()))
+            });
+            document.push_str(&format!(
+                "\n<a id=\"{}\"></a>\n\n## {}\n",
+                anchors[note.name.as_str()],
+                note.title
+            ));
+            if !body.is_empty() {
+                document.push_str(&format!("\n{body}\n"));
+            }
+        }
+        document
+    }
+    /// Renders the notes as a standalone HTML document, each under a heading
+    /// anchored at its name, with Obsidian's syntax rendered.
+    pub fn to_html(&self) -> String {
+        let title = format!("Notes from {} to {}", self.from, self.to);
+        let mut body = format!("<h1>{title}</h1>\n");
+        for note in &self.notes {
+            body.push_str(&format!(
+                "<h2 id=\"{}\">{}</h2>\n{}",
+                slugify(&note.name),
+                note.title,
+                self.renderer
+                    .render_anchored(&note.body, &slugify(&note.name))
+            ));
+        }
+        Renderer::to_document(&title, &body)
+    }
+}
+
+/// Builds a heading from the date of the periodical's note,
+/// e.g. `Tuesday, December 30, 2025` or `Week 1, 2026`.
+fn format_title(config: &AppConfig, period: Periodical, date: NaiveDate) -> String {
+    match period {
+        Periodical::Day => date.format("%A, %B %-d, %Y").to_string(),
+        Periodical::Week => {
+            let (year, week) = config
+                .get_week_numbering()
+                .number(date, config.get_week_start());
+            format!("Week {week}, {year}")
+        }
+        Periodical::Month => date.format("%B %Y").to_string(),
+        Periodical::Year => date.format("%Y").to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::write_test_vault;
+
+    fn config(files: &[(&str, &str)]) -> anyhow::Result<(tempfile::TempDir, AppConfig)> {
+        let vault = write_test_vault(files)?;
+        let config = AppConfig::builder(vault.path())
+            .dir(Periodical::Day, "day")
+            .fmt(Periodical::Week, "{start} to {end}")
+            .try_build()?;
+        Ok((vault, config))
+    }
+
+    #[test]
+    fn test_export_markdown() -> anyhow::Result<()> {
+        let desc = "Test exporting notes in order, with links pointing inside the export";
+        let (_vault, config) = config(&[
+            (
+                "day/2025-12-30.md",
+                "[[2025-12-29]] - [[2025-12-31]]\n\nsee [[2025-12-31|tomorrow]] and [[project]]\n",
+            ),
+            (
+                "day/2025-12-31.md",
+                "---\ntags: [log]\n---\n[[2025-12-30]] - [[2026-01-01]]\n\nback to [[day/2025-12-30#Tasks]]",
+            ),
+            ("day/2026-01-01.md", "out of range"),
+        ])?;
+        let from = NaiveDate::from_ymd_opt(2025, 12, 29).unwrap();
+        let to = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+        let got = Export::try_new(&config, Periodical::Day, from, to)?.render(ExportFormat::Md);
+        let want = [
+            "# Notes from 2025-12-29 to 2025-12-31",
+            "",
+            "<a id=\"2025-12-30\"></a>",
+            "",
+            "## Tuesday, December 30, 2025",
+            "",
+            "see [tomorrow](#2025-12-31) and [[project]]",
+            "",
+            "<a id=\"2025-12-31\"></a>",
+            "",
+            "## Wednesday, December 31, 2025",
+            "",
+            "back to [day/2025-12-30 > Tasks](#2025-12-30)",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(want, got, "{desc}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_html() -> anyhow::Result<()> {
+        let desc = "Test exporting weeks as a standalone HTML document";
+        let (_vault, config) = config(&[
+            ("2025-12-29 to 2026-01-04.md", "### Tasks\n\n- [ ] plan\n"),
+            (
+                "2026-01-05 to 2026-01-11.md",
+                "### Tasks\n\nafter [[2025-12-29 to 2026-01-04]], see [[#Tasks]] and [[2025-12-29 to 2026-01-04#Tasks|last]]",
+            ),
+        ])?;
+        let from = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let got = Export::try_new(&config, Periodical::Week, from, to)?.render(ExportFormat::Html);
+
+        [
+            "<!DOCTYPE html>",
+            "<title>Notes from 2026-01-01 to 2026-01-05</title>",
+            "<h2 id=\"2025-12-29-to-2026-01-04\">Week 1, 2026</h2>",
+            "<h2 id=\"2026-01-05-to-2026-01-11\">Week 2, 2026</h2>",
+            "<input disabled=\"\" type=\"checkbox\"/>",
+            "<a href=\"#2025-12-29-to-2026-01-04\">2025-12-29 to 2026-01-04</a>",
+            // headings of every note get their own ids
+            "<h3 id=\"2025-12-29-to-2026-01-04-tasks\">Tasks</h3>",
+            "<h3 id=\"2026-01-05-to-2026-01-11-tasks\">Tasks</h3>",
+            "<a href=\"#2026-01-05-to-2026-01-11-tasks\">#Tasks</a>",
+            "<a href=\"#2025-12-29-to-2026-01-04-tasks\">last</a>",
+        ]
+        .iter()
+        .for_each(|want| assert!(got.contains(want), "{desc}: {want}\n{got}"));
+        Ok(())
+    }
+}
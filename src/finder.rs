@@ -0,0 +1,308 @@
+use std::{
+    cmp::Reverse,
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use fuzzy_matcher::{FuzzyMatcher, skim::SkimMatcherV2};
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::NoteFinder;
+}
+
+/// Score bonus of a note modified right now, lost over as many days.
+const RECENCY_BONUS: i64 = 30;
+/// Most notes offered by the picker.
+const PICKER_LIMIT: usize = 10;
+
+/// Note matching a query, with what it matched on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FoundNote {
+    path: PathBuf,
+    /// File name or alias the query matched best.
+    matched: String,
+    exact: bool,
+    score: i64,
+}
+
+impl FoundNote {
+    /// Getter for the note's absolute path.
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+    /// Whether the file name or an alias equals the query, ignoring case.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+}
+
+/// Fuzzy finds notes anywhere in the vault by file name or frontmatter alias.
+#[derive(Debug, Clone, Copy)]
+pub struct NoteFinder<'a> {
+    config: &'a AppConfig,
+}
+
+impl<'a> NoteFinder<'a> {
+    pub fn new(config: &'a AppConfig) -> Self {
+        Self { config }
+    }
+    /// Attempts to find every note matching the query, best match first.
+    /// Notes are ranked by their best fuzzy match, with a bonus for
+    /// notes modified in the last days before the clock's time.
+    pub fn try_find(&self, query: &str, clock: &dyn Clock) -> Result<Vec<FoundNote>, RuntimeError> {
+        let matcher = SkimMatcherV2::default().ignore_case();
+        let now = clock.now();
+        let mut found = vec![];
+        for path in list_notes(self.config.get_vault_root())? {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let contents = std::fs::read_to_string(&path).unwrap_or_default();
+            let aliases = match split_frontmatter(&contents) {
+                (Some(frontmatter), _) => parse_aliases(frontmatter),
+                (None, _) => vec![],
+            };
+            let best = std::iter::once(name)
+                .chain(aliases)
+                .filter_map(|candidate| {
+                    let score = matcher.fuzzy_match(&candidate, query)?;
+                    Some((score, candidate))
+                })
+                .max_by_key(|(score, _)| *score);
+            let Some((score, matched)) = best else {
+                continue;
+            };
+            // notes that can't be read, like dangling symlinks, are skipped
+            let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) else {
+                continue;
+            };
+            found.push(FoundNote {
+                exact: matched.eq_ignore_ascii_case(query.trim()),
+                score: score + recency_bonus(now, modified.into()),
+                path,
+                matched,
+            });
+        }
+        found.sort_by_key(|note| (Reverse(note.exact), Reverse(note.score)));
+        Ok(found)
+    }
+}
+
+/// Linearly decaying bonus for notes modified shortly before now.
+fn recency_bonus(now: DateTime<Utc>, modified: DateTime<Utc>) -> i64 {
+    (RECENCY_BONUS - (now - modified).num_days().max(0)).max(0)
+}
+
+/// Picks the only note matching the query exactly, if any.
+/// Returns an error listing the notes if several match exactly.
+pub(crate) fn exact_match<'n>(
+    found: &'n [FoundNote],
+    query: &str,
+) -> Result<Option<&'n FoundNote>, RuntimeError> {
+    match found
+        .iter()
+        .take_while(|note| note.is_exact())
+        .collect::<Vec<_>>()[..]
+    {
+        [] => Ok(None),
+        [note] => Ok(Some(note)),
+        ref exact => Err(RuntimeError::Ambiguous {
+            query: query.to_string(),
+            notes: exact.iter().map(|note| note.path.clone()).collect(),
+        }),
+    }
+}
+
+/// Lists the best matches, numbered, and asks which one to open.
+/// Returns None if the answer isn't one of the numbers.
+pub(crate) fn pick<'n>(
+    found: &'n [FoundNote],
+    config: &AppConfig,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<Option<&'n FoundNote>, RuntimeError> {
+    let found = &found[..found.len().min(PICKER_LIMIT)];
+    for (i, note) in found.iter().enumerate() {
+        let path = note
+            .path
+            .strip_prefix(config.get_vault_root())
+            .unwrap_or(&note.path);
+        writeln!(output, "{:>2}) {}", i + 1, path.display())?;
+    }
+    write!(output, "Open which note? [1-{}]: ", found.len())?;
+    output.flush()?;
+
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    let note = answer
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|i| found.get(i.checked_sub(1)?));
+    Ok(note)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::vault::write_test_vault;
+
+    fn vault(files: &[(&str, &str)]) -> anyhow::Result<(tempfile::TempDir, AppConfig)> {
+        let vault = write_test_vault(files)?;
+        let config = AppConfig::builder(vault.path()).try_build()?;
+        Ok((vault, config))
+    }
+
+    #[test]
+    fn test_find() -> anyhow::Result<()> {
+        let desc = "Test finding notes by file name and alias";
+        let (vault, config) = vault(&[
+            ("projects/second-brain.md", ""),
+            ("meetings/standup.md", "---\naliases: [Daily Sync]\n---\n"),
+            ("inbox.md", ""),
+            ("2025-12-30.md", ""),
+        ])?;
+        let clock = SystemClock;
+        let finder = NoteFinder::new(&config);
+        let test_cases = [
+            ("brain", vec!["projects/second-brain.md"]),
+            ("daily sync", vec!["meetings/standup.md"]),
+            ("STANDUP", vec!["meetings/standup.md"]),
+            ("2025", vec!["2025-12-30.md"]),
+            ("nothing", vec![]),
+        ];
+        test_cases.into_iter().try_for_each(|(query, want)| {
+            let got = finder
+                .try_find(query, &clock)?
+                .into_iter()
+                .map(|note| note.path)
+                .collect::<Vec<_>>();
+            let want = want
+                .iter()
+                .map(|file| vault.path().join(file))
+                .collect::<Vec<_>>();
+            assert_eq!(want, got, "{desc}: {query}");
+            anyhow::Ok(())
+        })?;
+
+        let got = finder.try_find("daily sync", &clock)?;
+        assert_eq!("Daily Sync", got[0].matched, "{desc}: alias");
+        assert!(got[0].is_exact(), "{desc}: exact alias");
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank() -> anyhow::Result<()> {
+        let desc = "Test exact matches rank first, then match quality and recency";
+        let (vault, config) = vault(&[("review.md", ""), ("reviews.md", ""), ("rev.md", "")])?;
+        let clock = SystemClock;
+        let got = NoteFinder::new(&config)
+            .try_find("review", &clock)?
+            .into_iter()
+            .map(|note| note.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                vault.path().join("review.md"),
+                vault.path().join("reviews.md")
+            ],
+            got,
+            "{desc}"
+        );
+
+        let now = Utc.with_ymd_and_hms(2025, 12, 30, 9, 0, 0).unwrap();
+        let test_cases = [
+            (now, RECENCY_BONUS),
+            (now - chrono::Days::new(10), RECENCY_BONUS - 10),
+            (now - chrono::Days::new(100), 0),
+        ];
+        test_cases.into_iter().for_each(|(modified, want)| {
+            assert_eq!(want, recency_bonus(now, modified), "{desc}: {modified}");
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_pick() -> anyhow::Result<()> {
+        let desc = "Test picking a note by its number";
+        let (vault, config) = vault(&[("meeting-a.md", ""), ("meeting-b.md", "")])?;
+        let found = NoteFinder::new(&config).try_find("meeting", &SystemClock)?;
+        let test_cases = [
+            ("2\n", Some(&found[1])),
+            ("\n", None),
+            ("3\n", None),
+            ("0\n", None),
+        ];
+        test_cases.into_iter().try_for_each(|(answer, want)| {
+            let mut output = vec![];
+            let got = pick(&found, &config, &mut answer.as_bytes(), &mut output)?;
+            assert_eq!(want, got, "{desc}: {answer:?}");
+
+            let output = String::from_utf8(output)?;
+            assert!(
+                output.starts_with(&format!(
+                    " 1) {}",
+                    found[0].path.strip_prefix(vault.path())?.display()
+                )),
+                "{desc}: {output}"
+            );
+            anyhow::Ok(())
+        })
+    }
+
+    #[test]
+    fn test_unreadable_note() -> anyhow::Result<()> {
+        let desc = "Test notes that can't be read are skipped";
+        let (vault, config) = vault(&[("garden.md", "")])?;
+        std::os::unix::fs::symlink(vault.path().join("gone.md"), vault.path().join("garage.md"))?;
+
+        let got = NoteFinder::new(&config)
+            .try_find("gar", &SystemClock)?
+            .into_iter()
+            .map(|note| note.path)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![vault.path().join("garden.md")], got, "{desc}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_exact_match() -> anyhow::Result<()> {
+        let desc = "Test picking the exact match, failing if there are several";
+        let (vault, config) = vault(&[
+            ("standup.md", ""),
+            ("work/standup.md", ""),
+            ("meetings/sync.md", ""),
+            ("syncing.md", ""),
+        ])?;
+        let finder = NoteFinder::new(&config);
+
+        let found = finder.try_find("sync", &SystemClock)?;
+        let got = exact_match(&found, "sync")?.map(FoundNote::get_path);
+        assert_eq!(
+            Some(vault.path().join("meetings/sync.md").as_path()),
+            got,
+            "{desc}: one"
+        );
+
+        let found = finder.try_find("syn", &SystemClock)?;
+        assert_eq!(None, exact_match(&found, "syn")?, "{desc}: none");
+
+        let found = finder.try_find("standup", &SystemClock)?;
+        match exact_match(&found, "standup") {
+            Err(RuntimeError::Ambiguous { query, mut notes }) => {
+                notes.sort();
+                assert_eq!("standup", query, "{desc}: query");
+                let want = ["standup.md", "work/standup.md"].map(|f| vault.path().join(f));
+                assert_eq!(want.to_vec(), notes, "{desc}: notes");
+            }
+            got => panic!("{desc}: unexpected result {got:?}"),
+        }
+        Ok(())
+    }
+}
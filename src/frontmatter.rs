@@ -0,0 +1,105 @@
+pub mod prelude {
+    pub use super::{parse_aliases, split_frontmatter};
+}
+
+/// Splits a note into its YAML frontmatter, without the `---` fences,
+/// and the body following it.
+/// Returns no frontmatter if the note doesn't start with a fenced block.
+pub fn split_frontmatter(contents: &str) -> (Option<&str>, &str) {
+    let Some(rest) = contents
+        .strip_prefix("---\n")
+        .or_else(|| contents.strip_prefix("---\r\n"))
+    else {
+        return (None, contents);
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, contents)
+}
+
+/// Parses the `aliases` of a note's frontmatter, Obsidian's legacy
+/// `alias` key included. Both flow lists, `[a, b]`, and block lists are read.
+pub fn parse_aliases(frontmatter: &str) -> Vec<String> {
+    let mut aliases = vec![];
+    let mut in_list = false;
+    for line in frontmatter.lines() {
+        if in_list {
+            match line.trim_start().strip_prefix("- ") {
+                Some(alias) => aliases.push(unquote(alias)),
+                None if line.trim().is_empty() => (),
+                None => in_list = false,
+            }
+            continue;
+        }
+        let value = ["aliases:", "alias:"]
+            .iter()
+            .find_map(|key| line.strip_prefix(key))
+            .map(str::trim);
+        match value {
+            Some("") => in_list = true,
+            Some(value) => match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                Some(list) => aliases.extend(
+                    list.split(',')
+                        .map(unquote)
+                        .filter(|alias| !alias.is_empty()),
+                ),
+                None => aliases.push(unquote(value)),
+            },
+            None => (),
+        }
+    }
+    aliases
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| value.strip_prefix(*quote)?.strip_suffix(*quote))
+        .unwrap_or(value)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_frontmatter() {
+        let desc = "Test splitting frontmatter from the body";
+        let test_cases = [
+            ("---\ntags: a\n---\n# Body", (Some("tags: a\n"), "# Body")),
+            ("---\n---\nbody", (Some(""), "body")),
+            ("# Body\n---\n", (None, "# Body\n---\n")),
+            ("---\nnever closed", (None, "---\nnever closed")),
+        ];
+        test_cases.into_iter().for_each(|(contents, want)| {
+            assert_eq!(want, split_frontmatter(contents), "{desc}: {contents:?}");
+        });
+    }
+
+    #[test]
+    fn test_parse_aliases() {
+        let desc = "Test parsing aliases from frontmatter";
+        let test_cases = [
+            (
+                "aliases: [Weekly Review, 'review']",
+                vec!["Weekly Review", "review"],
+            ),
+            (
+                "tags: [a]\naliases:\n  - \"Meeting notes\"\n  - standup\ncssclass: wide",
+                vec!["Meeting notes", "standup"],
+            ),
+            ("alias: inbox", vec!["inbox"]),
+            ("aliases: []\ntags: [a]", vec![]),
+        ];
+        test_cases.into_iter().for_each(|(frontmatter, want)| {
+            assert_eq!(want, parse_aliases(frontmatter), "{desc}: {frontmatter:?}");
+        });
+    }
+}
@@ -0,0 +1,177 @@
+use std::path::Path;
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use git2::{Commit, ErrorCode, FileMode, Oid, Repository, Signature, build::TreeUpdateBuilder};
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::GitConfig;
+    pub(crate) use super::try_commit_note;
+}
+
+const DEFAULT_MESSAGE: &str = "{period}: {note}";
+/// Author used when the repository has no `user.name` or `user.email`.
+const FALLBACK_NAME: &str = "second-brain";
+const FALLBACK_EMAIL: &str = "second-brain@localhost";
+
+/// Configuration of committing notes to the git repository the vault is in.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct GitConfig {
+    pub(crate) auto_commit: Option<bool>,
+    pub(crate) message: Option<String>,
+}
+
+impl GitConfig {
+    /// Getter for whether notes are committed after the editor exits.
+    /// Defaults to false.
+    pub fn get_auto_commit(&self) -> bool {
+        self.auto_commit.unwrap_or_default()
+    }
+    /// Getter for the commit message template.
+    pub fn get_message(&self) -> &str {
+        self.message.as_deref().unwrap_or(DEFAULT_MESSAGE)
+    }
+    /// Fills in the `{period}`, `{note}` and `{date}` tokens of the
+    /// commit message template.
+    pub fn format_message(&self, period: Periodical, note: &str, date: NaiveDate) -> String {
+        self.get_message()
+            .replace("{period}", &period.to_string())
+            .replace("{note}", note)
+            .replace("{date}", &date.to_string())
+    }
+}
+
+/// Commits the note to the repository containing the vault.
+/// Only the note goes into the commit, on top of HEAD's tree, so changes
+/// the user staged otherwise stay staged and uncommitted.
+/// Returns None without committing if the note didn't change since
+/// the last commit.
+pub(crate) fn try_commit_note(
+    config: &AppConfig,
+    period: Periodical,
+    date: DateTime<FixedOffset>,
+    path: &Path,
+) -> Result<Option<Oid>, RuntimeError> {
+    let repo = Repository::discover(config.get_vault_root())?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| git2::Error::from_str("can't commit to a bare repository"))?
+        .canonicalize()?;
+    let path = path.canonicalize()?;
+    let relative = path.strip_prefix(&workdir).map_err(|_| {
+        git2::Error::from_str(&format!("{} is outside of the repository", path.display()))
+    })?;
+
+    let parent = match repo.head() {
+        Ok(head) => Some(head.peel_to_commit()?),
+        Err(e) if matches!(e.code(), ErrorCode::UnbornBranch | ErrorCode::NotFound) => None,
+        Err(e) => return Err(e.into()),
+    };
+    let base = match &parent {
+        Some(commit) => commit.tree()?,
+        None => repo.find_tree(repo.treebuilder(None)?.write()?)?,
+    };
+    let blob = repo.blob_path(&path)?;
+    let tree_id = TreeUpdateBuilder::new()
+        .upsert(relative, blob, FileMode::Blob)
+        .create_updated(&repo, &base)?;
+    if parent
+        .as_ref()
+        .is_some_and(|commit| commit.tree_id() == tree_id)
+    {
+        return Ok(None);
+    }
+
+    let tree = repo.find_tree(tree_id)?;
+    let signature = repo
+        .signature()
+        .or_else(|_| Signature::now(FALLBACK_NAME, FALLBACK_EMAIL))?;
+    let start = period.start_of(date.date_naive(), config.get_week_start());
+    let message =
+        config
+            .get_git_config()
+            .format_message(period, &config.format_date(period, date), start);
+    let parents = parent.iter().collect::<Vec<&Commit>>();
+    let oid = repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &message,
+        &tree,
+        &parents,
+    )?;
+    // keeps the note from showing up as changed against the new HEAD
+    let mut index = repo.index()?;
+    index.add_path(relative)?;
+    index.write()?;
+    Ok(Some(oid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_config::TomlConfig;
+
+    #[test]
+    fn test_format_message() {
+        let desc = "Test commit message tokens";
+        let date = NaiveDate::from_ymd_opt(2025, 12, 29).unwrap();
+        let test_cases = [
+            (None, "week: 2026-W01"),
+            (Some("notes({period}): {date}"), "notes(week): 2025-12-29"),
+        ];
+        test_cases.into_iter().for_each(|(message, want)| {
+            let config = GitConfig {
+                auto_commit: Some(true),
+                message: message.map(String::from),
+            };
+            let got = config.format_message(Periodical::Week, "2026-W01", date);
+            assert_eq!(want, got, "{desc}: {message:?}");
+        });
+    }
+
+    #[test]
+    fn test_commit_note() -> anyhow::Result<()> {
+        let desc = "Test committing notes to the vault's repository";
+        let vault = tempfile::tempdir()?;
+        let repo = Repository::init(vault.path())?;
+        let s = format!(
+            "[vault]\ndir = {:?}\n[git]\nauto_commit = true\nmessage = \"daily: {{note}}\"",
+            vault.path()
+        );
+        let config = AppConfig::try_from(toml::from_str::<TomlConfig>(&s)?)?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 10, 18).unwrap());
+        let path = Periodical::Day.try_create(&config, &RunOptions::default(), date)?;
+
+        let first = try_commit_note(&config, Periodical::Day, date, &path)?;
+        let commit = repo.find_commit(first.expect("first commit"))?;
+        assert_eq!("daily: 2025-10-18", commit.message()?, "{desc}: message");
+        assert!(
+            commit.tree()?.get_name("2025-10-18.md").is_some(),
+            "{desc}: note committed"
+        );
+
+        std::fs::write(vault.path().join("staged.md"), "staged")?;
+        let mut index = repo.index()?;
+        index.add_path(Path::new("staged.md"))?;
+        index.write()?;
+        let unchanged = try_commit_note(&config, Periodical::Day, date, &path)?;
+        assert_eq!(None, unchanged, "{desc}: unchanged note is skipped");
+
+        std::fs::write(&path, "edited")?;
+        let edited = try_commit_note(&config, Periodical::Day, date, &path)?;
+        let commit = repo.find_commit(edited.expect("second commit"))?;
+        assert_eq!(first, commit.parent_id(0).ok(), "{desc}: parent");
+        assert!(
+            commit.tree()?.get_name("staged.md").is_none(),
+            "{desc}: staged changes aren't committed"
+        );
+        assert!(
+            repo.index()?.get_path(Path::new("staged.md"), 0).is_some(),
+            "{desc}: staged changes stay staged"
+        );
+        Ok(())
+    }
+}
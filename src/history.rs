@@ -0,0 +1,288 @@
+use std::{
+    env,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{History, HistoryEntry};
+}
+
+/// File name of the history inside of `$XDG_STATE_HOME/second-brain`.
+const XDG_FILE: &str = "history.jsonl";
+/// Most entries kept in the history file.
+const MAX_ENTRIES: usize = 500;
+/// Entries older than this many days are dropped from the history file.
+const MAX_AGE_DAYS: i64 = 90;
+
+/// Notes opened through `Periodical::open`, kept as one JSON object per line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct History(PathBuf);
+
+/// A note opened at a point in time.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HistoryEntry {
+    opened: DateTime<Utc>,
+    period: Periodical,
+    /// Start of the note's period.
+    date: NaiveDate,
+    path: PathBuf,
+}
+
+impl HistoryEntry {
+    pub fn new(opened: DateTime<Utc>, period: Periodical, date: NaiveDate, path: &Path) -> Self {
+        Self {
+            opened,
+            period,
+            date,
+            path: path.to_path_buf(),
+        }
+    }
+    /// Getter for when the note was opened.
+    pub fn get_opened(&self) -> DateTime<Utc> {
+        self.opened
+    }
+    /// Getter for the note's periodical.
+    pub fn get_period(&self) -> Periodical {
+        self.period
+    }
+    /// Getter for the start of the note's period.
+    pub fn get_date(&self) -> NaiveDate {
+        self.date
+    }
+    /// Getter for the note's absolute path.
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl History {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self(path.into())
+    }
+    /// Attempts to locate the history file at
+    /// `$XDG_STATE_HOME/second-brain/history.jsonl`,
+    /// with the state home defaulting to `~/.local/state`.
+    /// Returns None if neither `$XDG_STATE_HOME` nor `$HOME` is set.
+    pub fn try_from_env() -> Result<Option<Self>, ConfigError> {
+        Self::try_from_state_home(
+            env::var_os("XDG_STATE_HOME").map(PathBuf::from),
+            dirs::home_dir(),
+        )
+    }
+    /// Attempts to locate the history file in the state home, or in the
+    /// home directory's `.local/state` if no state home is set.
+    /// Returns an error if the state home isn't absolute or isn't a directory.
+    fn try_from_state_home(
+        state_home: Option<PathBuf>,
+        home: Option<PathBuf>,
+    ) -> Result<Option<Self>, ConfigError> {
+        let state_home = match state_home.filter(|p| !p.as_os_str().is_empty()) {
+            Some(dir) if !dir.is_absolute() || (dir.exists() && !dir.is_dir()) => {
+                return Err(ConfigError::InvalidStateDir(dir));
+            }
+            Some(dir) => dir,
+            None => match home {
+                Some(home) => home.join(".local").join("state"),
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(Self(state_home.join("second-brain").join(XDG_FILE))))
+    }
+    /// Getter for the history file's path.
+    pub fn get_path(&self) -> &Path {
+        &self.0
+    }
+    /// Attempts to read every entry, oldest first.
+    /// Lines that can't be parsed are skipped, and a missing file is empty.
+    pub fn try_load(&self) -> Result<Vec<HistoryEntry>, RuntimeError> {
+        let contents = match std::fs::read_to_string(&self.0) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            contents => contents?,
+        };
+        Ok(contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+    /// Attempts to append the entry, pruning entries older than 90 days
+    /// and all but the latest 500.
+    /// Concurrent runs take turns through a lock file next to the history,
+    /// so none of their entries get lost.
+    pub fn try_record(&self, entry: HistoryEntry) -> Result<(), RuntimeError> {
+        let parent = self.0.parent().unwrap_or(Path::new("."));
+        std::fs::create_dir_all(parent)?;
+        // released when dropped at the end of the write
+        let lock = File::create(self.0.with_extension("lock"))?;
+        lock.lock()?;
+
+        let mut entries = self.try_load()?;
+        let oldest = entry.opened - TimeDelta::days(MAX_AGE_DAYS);
+        entries.retain(|e| e.opened >= oldest);
+        entries.push(entry);
+        let entries = &entries[entries.len().saturating_sub(MAX_ENTRIES)..];
+
+        // written to a temp file first, so concurrent runs never see a partial history
+        let mut f = tempfile::NamedTempFile::new_in(parent)?;
+        for entry in entries {
+            writeln!(f, "{}", serde_json::to_string(entry)?)?;
+        }
+        f.persist(&self.0).map_err(|e| e.error)?;
+        Ok(())
+    }
+    /// Attempts to list the most recently opened notes, latest first.
+    /// Every note is listed once, and notes that no longer exist or were
+    /// opened more than 90 days before now are skipped.
+    pub fn try_recent(
+        &self,
+        limit: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<HistoryEntry>, RuntimeError> {
+        let oldest = now - TimeDelta::days(MAX_AGE_DAYS);
+        let mut recent: Vec<HistoryEntry> = vec![];
+        for entry in self.try_load()?.into_iter().rev() {
+            if recent.len() == limit {
+                break;
+            }
+            if entry.opened >= oldest
+                && entry.path.exists()
+                && !recent.iter().any(|e| e.path == entry.path)
+            {
+                recent.push(entry);
+            }
+        }
+        Ok(recent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn entry(vault: &Path, opened: DateTime<Utc>, file: &str) -> HistoryEntry {
+        HistoryEntry::new(
+            opened,
+            Periodical::Day,
+            opened.date_naive(),
+            &vault.join(file),
+        )
+    }
+
+    #[test]
+    fn test_recent() -> anyhow::Result<()> {
+        let desc = "Test listing recent notes, latest first";
+        let dir = tempfile::tempdir()?;
+        let history = History::new(dir.path().join("state/history.jsonl"));
+        let now = Utc.with_ymd_and_hms(2025, 12, 30, 9, 0, 0).unwrap();
+        assert_eq!(
+            Vec::<HistoryEntry>::new(),
+            history.try_recent(10, now)?,
+            "{desc}: missing"
+        );
+
+        ["a.md", "b.md", "c.md"]
+            .iter()
+            .try_for_each(|file| std::fs::write(dir.path().join(file), ""))?;
+        let opened = [
+            ("a.md", 4),
+            ("deleted.md", 3),
+            ("b.md", 2),
+            ("a.md", 1),
+            ("c.md", 0),
+        ];
+        opened.iter().try_for_each(|(file, hours_ago)| {
+            history.try_record(entry(dir.path(), now - TimeDelta::hours(*hours_ago), file))
+        })?;
+
+        let got = history
+            .try_recent(10, now)?
+            .into_iter()
+            .map(|e| e.path)
+            .collect::<Vec<_>>();
+        let want = ["c.md", "a.md", "b.md"].map(|file| dir.path().join(file));
+        assert_eq!(want.to_vec(), got, "{desc}");
+        assert_eq!(2, history.try_recent(2, now)?.len(), "{desc}: limit");
+        let later = now + TimeDelta::days(MAX_AGE_DAYS) - TimeDelta::minutes(30);
+        let got = history
+            .try_recent(10, later)?
+            .into_iter()
+            .map(|e| e.path)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![dir.path().join("c.md")], got, "{desc}: age");
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune() -> anyhow::Result<()> {
+        let desc = "Test pruning the history by age and count";
+        let dir = tempfile::tempdir()?;
+        let history = History::new(dir.path().join("history.jsonl"));
+        let now = Utc.with_ymd_and_hms(2025, 12, 30, 9, 0, 0).unwrap();
+
+        history.try_record(entry(dir.path(), now - TimeDelta::days(91), "old.md"))?;
+        history.try_record(entry(dir.path(), now - TimeDelta::days(89), "kept.md"))?;
+        history.try_record(entry(dir.path(), now, "new.md"))?;
+        let got = history.try_load()?;
+        assert_eq!(2, got.len(), "{desc}: age");
+        assert_eq!(dir.path().join("kept.md"), got[0].path, "{desc}: age");
+
+        let full = (0..MAX_ENTRIES)
+            .map(|i| serde_json::to_string(&entry(dir.path(), now, &format!("{i}.md"))))
+            .collect::<Result<Vec<_>, _>>()?;
+        std::fs::write(history.get_path(), full.join("\n"))?;
+        history.try_record(entry(dir.path(), now, "new.md"))?;
+        let got = history.try_load()?;
+        assert_eq!(MAX_ENTRIES, got.len(), "{desc}: count");
+        assert_eq!(dir.path().join("1.md"), got[0].path, "{desc}: count");
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_home() -> anyhow::Result<()> {
+        let desc = "Test locating the history in the state home";
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("file");
+        std::fs::write(&file, "")?;
+        let history = |dir: &Path| History::new(dir.join("second-brain").join(XDG_FILE));
+        let test_cases = [
+            (
+                Some(dir.path().to_path_buf()),
+                None,
+                Ok(Some(history(dir.path()))),
+            ),
+            (
+                None,
+                Some(dir.path().to_path_buf()),
+                Ok(Some(history(&dir.path().join(".local/state")))),
+            ),
+            (
+                Some(PathBuf::new()),
+                Some(dir.path().to_path_buf()),
+                Ok(Some(history(&dir.path().join(".local/state")))),
+            ),
+            (None, None, Ok(None)),
+            (Some(PathBuf::from("state")), None, Err("state")),
+            (Some(file.clone()), None, Err(file.to_str().unwrap())),
+        ];
+        test_cases.into_iter().for_each(|(state_home, home, want)| {
+            let case = format!("{state_home:?} {home:?}");
+            let got = History::try_from_state_home(state_home, home);
+            match (want, got) {
+                (Ok(want), Ok(got)) => assert_eq!(want, got, "{desc}: {case}"),
+                (Err(want), Err(ConfigError::InvalidStateDir(got))) => {
+                    assert_eq!(Path::new(want), got, "{desc}: {case}")
+                }
+                (want, got) => panic!("{desc}: {case}: want {want:?}, got {got:?}"),
+            }
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use strum_macros::Display;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{Hook, HookConfig};
+}
+
+/// Shell commands run at points of opening a note.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct HookConfig {
+    pub(crate) pre_create: Option<String>,
+    pub(crate) post_create: Option<String>,
+    pub(crate) post_edit: Option<String>,
+}
+
+/// Point of opening a note a hook runs at.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Hook {
+    /// Before a missing note is written, failing aborts the write.
+    PreCreate,
+    /// After a missing note is written.
+    PostCreate,
+    /// After the editor exits.
+    PostEdit,
+}
+
+impl HookConfig {
+    /// Getter for the shell command configured for the hook.
+    pub fn get(&self, hook: Hook) -> Option<&str> {
+        match hook {
+            Hook::PreCreate => self.pre_create.as_deref(),
+            Hook::PostCreate => self.post_create.as_deref(),
+            Hook::PostEdit => self.post_edit.as_deref(),
+        }
+    }
+    /// Runs the hook's command with `sh -c`, if one is configured.
+    /// The note's path, periodical, start date and the vault root are passed in
+    /// as the `SB_NOTE`, `SB_PERIOD`, `SB_DATE` and `SB_VAULT` environment variables.
+    /// Returns an error with the hook's stderr if it exits unsuccessfully.
+    pub(crate) fn try_run(
+        &self,
+        hook: Hook,
+        config: &AppConfig,
+        period: Periodical,
+        date: DateTime<FixedOffset>,
+        path: &Path,
+    ) -> Result<(), RuntimeError> {
+        let Some(command) = self.get(hook) else {
+            return Ok(());
+        };
+        let start = period.start_of(date.date_naive(), config.get_week_start());
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .current_dir(config.get_vault_root())
+            .env("SB_NOTE", path)
+            .env("SB_PERIOD", period.to_string())
+            .env("SB_DATE", start.to_string())
+            .env("SB_VAULT", config.get_vault_root())
+            .stdin(Stdio::null())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::piped())
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        if !output.status.success() {
+            return Err(RuntimeError::Hook { hook, stderr });
+        }
+        // hooks may warn without failing
+        std::io::stderr().write_all(stderr.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::app_config::TomlConfig;
+
+    fn config(vault: &Path, hooks: &str) -> anyhow::Result<AppConfig> {
+        let s = format!(
+            "[vault]\ndir = {vault:?}\n[periodical.week]\ndir = \"week\"\n[hooks]\n{hooks}"
+        );
+        Ok(AppConfig::try_from(toml::from_str::<TomlConfig>(&s)?)?)
+    }
+
+    #[test]
+    fn test_hook_env() -> anyhow::Result<()> {
+        let desc = "Test hooks get the note's details as environment variables";
+        let vault = tempfile::tempdir()?;
+        let config = config(
+            vault.path(),
+            "post_create = 'echo \"$SB_PERIOD $SB_DATE $SB_NOTE $SB_VAULT\" > env.txt'",
+        )?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+        let path = Periodical::Week.try_create(&config, &RunOptions::default(), date)?;
+
+        let want = format!(
+            "week 2025-12-29 {} {}\n",
+            path.display(),
+            vault.path().display()
+        );
+        let got = std::fs::read_to_string(vault.path().join("env.txt"))?;
+        assert_eq!(want, got, "{desc}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_pre_create() -> anyhow::Result<()> {
+        let desc = "Test failing pre-create hooks abort writing the note";
+        let vault = tempfile::tempdir()?;
+        let config = config(vault.path(), "pre_create = 'echo nope >&2; exit 1'")?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+
+        let got = Periodical::Week.try_create(&config, &RunOptions::default(), date);
+        match got {
+            Err(Status::RuntimeError(RuntimeError::Hook { hook, stderr })) => {
+                assert_eq!(Hook::PreCreate, hook, "{desc}: hook");
+                assert_eq!("nope\n", stderr, "{desc}: stderr");
+            }
+            got => panic!("{desc}: unexpected result {got:?}"),
+        }
+        assert!(
+            !vault.path().join("week").exists(),
+            "{desc}: nothing written"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_post_create_existing() -> anyhow::Result<()> {
+        let desc = "Test post-create hooks skip notes created by someone else";
+        let vault = tempfile::tempdir()?;
+        // the pre-create hook stands in for a process creating the note first
+        let config = config(
+            vault.path(),
+            "pre_create = 'mkdir -p week && echo theirs > \"$SB_NOTE\"'\npost_create = 'touch created'",
+        )?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+        let path = Periodical::Week.try_create(&config, &RunOptions::default(), date)?;
+
+        assert_eq!("theirs\n", std::fs::read_to_string(path)?, "{desc}: note");
+        assert!(
+            !vault.path().join("created").exists(),
+            "{desc}: post-create ran"
+        );
+        Ok(())
+    }
+}
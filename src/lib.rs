@@ -1,19 +1,106 @@
+//! Creates and opens Obsidian style periodical notes.
+//!
+//! Besides the `sb` binary, the prelude exposes the pieces it's built from,
+//! so other tools can reuse path resolution and note creation:
+//!
+//! - [`AppConfig::builder`](prelude::AppConfig::builder) configures a vault in code,
+//!   [`ConfigFile`](prelude::ConfigFile) loads the same config from TOML.
+//! - [`NoteResolver`](prelude::NoteResolver) turns a periodical and a date into
+//!   a note path, and note paths back into their periodical and date.
+//! - [`NoteWriter`](prelude::NoteWriter) renders, writes and opens notes,
+//!   with the [`RunOptions`](prelude::RunOptions) of a dry run or a history to record in.
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use second_brain::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let vault = std::env::temp_dir();
+//! let config = AppConfig::builder(&vault)
+//!     .dir(Periodical::Day, "day")
+//!     .fmt(Periodical::Week, "{start} to {end}")
+//!     .hook(Hook::PostEdit, "git add -A")
+//!     .try_build()?;
+//! let resolver = NoteResolver::new(&config);
+//! let date = NaiveDate::from_ymd_opt(2025, 12, 30).unwrap();
+//!
+//! assert_eq!(vault.join("day/2025-12-30.md"), resolver.resolve(Periodical::Day, date)?);
+//! assert_eq!("2025-12-29 to 2026-01-04", resolver.note_name(Periodical::Week, date));
+//! assert_eq!(Some("git add -A"), config.get_hook_config().get(Hook::PostEdit));
+//! # Ok(())
+//! # }
+//! ```
 pub mod prelude {
+    pub use super::CONFIG_ENV;
     pub use super::app_config::prelude::*;
+    pub(crate) use super::browse::prelude::*;
+    pub(crate) use super::calendar::prelude::*;
     pub use super::cli::prelude::*;
+    pub use super::clock::prelude::*;
+    pub use super::completions::COMPLETE_ENV;
+    pub(crate) use super::completions::prelude::*;
     pub use super::config_file::prelude::*;
+    pub(crate) use super::date_parser::prelude::*;
+    pub use super::diagnostic::prelude::*;
+    pub(crate) use super::dry_run::prelude::*;
+    pub(crate) use super::editor::prelude::*;
     pub use super::errors::prelude::*;
-    pub(crate) use super::periodic::prelude::*;
+    pub(crate) use super::export::prelude::*;
+    pub(crate) use super::finder::prelude::*;
+    pub(crate) use super::frontmatter::prelude::*;
+    pub use super::git::prelude::*;
+    pub use super::history::prelude::*;
+    pub use super::hooks::prelude::*;
+    pub(crate) use super::links::prelude::*;
+    pub(crate) use super::navigation::prelude::*;
+    pub use super::notes::prelude::*;
+    pub use super::periodic::prelude::*;
+    pub(crate) use super::render::prelude::*;
+    pub(crate) use super::resolved_config::prelude::*;
+    pub use super::rollup::prelude::*;
+    pub use super::run_options::prelude::*;
+    pub(crate) use super::stats::prelude::*;
+    pub use super::time_config::prelude::*;
+    pub(crate) use super::vault::prelude::*;
+    pub use super::week::prelude::*;
     pub(crate) use super::{DEFAULT_DAY, DEFAULT_MONTH, DEFAULT_WEEK, DEFAULT_YEAR};
 }
 
 pub mod app;
 mod app_config;
+mod browse;
+mod calendar;
 mod cli;
+mod clock;
+mod completions;
 mod config_file;
+mod date_parser;
+mod diagnostic;
+mod dry_run;
+mod editor;
 mod errors;
+mod export;
+mod finder;
+mod frontmatter;
+mod git;
+mod history;
+mod hooks;
+mod links;
+mod navigation;
+mod notes;
 mod periodic;
 mod periodic_config;
+mod render;
+mod resolved_config;
+mod rollup;
+mod run_options;
+mod stats;
+mod time_config;
+mod vault;
+mod week;
+
+/// Env variable that takes precedence when searching for a config file.
+pub const CONFIG_ENV: &str = "SECOND_BRAIN_CONFIG";
 
 const DEFAULT_DAY: &str = "%Y-%m-%d";
 const DEFAULT_WEEK: &str = "%Y-W%V";
@@ -0,0 +1,146 @@
+use std::{borrow::Cow, sync::LazyLock};
+
+use regex::{Captures, Regex};
+
+pub mod prelude {
+    pub use super::{WikiLink, replace_wikilinks, strip_navigation, wikilinks};
+}
+
+/// Matches `[[target]]`, `[[target#heading]]` and `[[target|alias]]`,
+/// embeds included, capturing the embed marker, target, heading and alias.
+static WIKILINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(!?)\[\[([^\]|#]*)(?:#([^\]|]*))?(?:\|([^\]]*))?\]\]").unwrap());
+/// Matches the `[[prev]] - [[next]]` heading written into new notes.
+static NAVIGATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\[\[[^\]]+\]\] - \[\[[^\]]+\]\]\r?\n(\r?\n)?").unwrap());
+
+/// Obsidian style link to another note, or an embed of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WikiLink<'a> {
+    /// Whether the link is an `![[embed]]`.
+    pub embed: bool,
+    /// Linked note or file, empty for links within the same note.
+    pub target: &'a str,
+    pub heading: Option<&'a str>,
+    pub alias: Option<&'a str>,
+}
+
+impl<'a> WikiLink<'a> {
+    fn from_captures(captures: &Captures<'a>) -> Self {
+        let group = |i| captures.get(i).map(|m| m.as_str().trim());
+        Self {
+            embed: group(1) == Some("!"),
+            target: group(2).unwrap_or_default(),
+            heading: group(3),
+            alias: group(4),
+        }
+    }
+    /// Text the link is shown as: the alias, or the target and heading.
+    pub fn get_text(&self) -> String {
+        match (self.alias, self.heading) {
+            (Some(alias), _) => alias.to_string(),
+            (None, Some(heading)) if self.target.is_empty() => heading.to_string(),
+            (None, Some(heading)) => format!("{} > {heading}", self.target),
+            (None, None) => self.target.to_string(),
+        }
+    }
+    /// File name of the target, without its folders or a `.md` extension.
+    pub fn get_note_name(&self) -> &'a str {
+        let name = self.target.rsplit('/').next().unwrap_or(self.target);
+        name.strip_suffix(".md").unwrap_or(name)
+    }
+    /// Whether the link points at another note, rather than at a heading
+    /// of the same note or an attachment like `diagram.png`.
+    /// Dots followed by anything but a short file extension, as in
+    /// `2025.12.30` or `v1.2 notes`, don't make a target an attachment.
+    pub fn is_note(&self) -> bool {
+        let name = self.get_note_name();
+        let attachment = name.rsplit_once('.').is_some_and(|(_, ext)| {
+            ext.len() <= 5
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && ext.chars().any(|c| c.is_ascii_alphabetic())
+        });
+        !name.is_empty() && !attachment
+    }
+}
+
+/// Iterates every wikilink and embed in the text.
+pub fn wikilinks(text: &str) -> impl Iterator<Item = WikiLink<'_>> {
+    WIKILINK
+        .captures_iter(text)
+        .map(|captures| WikiLink::from_captures(&captures))
+}
+
+/// Replaces every wikilink and embed the closure returns a replacement for.
+/// Links it returns None for are kept as they are.
+pub fn replace_wikilinks<'t>(
+    text: &'t str,
+    mut replace: impl FnMut(&WikiLink) -> Option<String>,
+) -> Cow<'t, str> {
+    WIKILINK.replace_all(text, |captures: &Captures| {
+        replace(&WikiLink::from_captures(captures)).unwrap_or(captures[0].to_string())
+    })
+}
+
+/// Strips the generated `[[prev]] - [[next]]` heading, and the blank line
+/// following it, from the start of a note.
+pub fn strip_navigation(contents: &str) -> &str {
+    match NAVIGATION.find(contents) {
+        Some(heading) => &contents[heading.end()..],
+        None => contents,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wikilinks() {
+        let desc = "Test extracting the notes wikilinks point at";
+        let test_cases = [
+            (
+                "see [[project]] and [[2025-W01|last week]]",
+                vec!["project", "2025-W01"],
+            ),
+            (
+                "![[diagram.png]] [[dir/note.md#Heading]] [[2025.12.30]]",
+                vec!["note", "2025.12.30"],
+            ),
+            ("[[#local heading]] [not a link]", vec![]),
+        ];
+        test_cases.into_iter().for_each(|(text, want)| {
+            let got = wikilinks(text)
+                .filter(WikiLink::is_note)
+                .map(|link| link.get_note_name())
+                .collect::<Vec<_>>();
+            assert_eq!(want, got, "{desc}: {text}");
+        });
+    }
+
+    #[test]
+    fn test_replace_wikilinks() {
+        let desc = "Test replacing wikilinks, keeping the ones without a replacement";
+        let text = "[[a]], [[dir/b.md#Tasks|tasks]], [[#Local]] and ![[c.png]]";
+        let got = replace_wikilinks(text, |link| {
+            (!link.embed).then(|| format!("<{}:{}>", link.get_note_name(), link.get_text()))
+        });
+        assert_eq!("<a:a>, <b:tasks>, <:Local> and ![[c.png]]", got, "{desc}");
+    }
+
+    #[test]
+    fn test_strip_navigation() {
+        let desc = "Test stripping the generated navigation heading";
+        let test_cases = [
+            (
+                "[[2025-12-29]] - [[2025-12-31]]\n\n## Tasks\n",
+                "## Tasks\n",
+            ),
+            ("[[2025-12-29]] - [[2025-12-31]]\n", ""),
+            ("## Tasks\n[[a]] - [[b]]\n", "## Tasks\n[[a]] - [[b]]\n"),
+        ];
+        test_cases.into_iter().for_each(|(contents, want)| {
+            assert_eq!(want, strip_navigation(contents), "{desc}: {contents:?}");
+        });
+    }
+}
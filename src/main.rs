@@ -1,14 +1,72 @@
-use clap::Parser;
+use std::process::ExitCode;
 
-use second_brain::prelude::*;
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
 
-fn main() -> Result<(), Status> {
-    let config_file = ConfigFile::try_from_env("SECOND_BRAIN_CONFIG")?.try_build()?;
-    let config = AppConfig::try_from(config_file)?;
-    let command = Args::parse().command.unwrap_or_default();
+use second_brain::{app::App, prelude::*};
+
+fn main() -> ExitCode {
+    // answers completion requests from the registered shell scripts
+    CompleteEnv::with_factory(Args::command)
+        .var(COMPLETE_ENV)
+        .bin("sb")
+        .complete();
+
+    let args = Args::parse();
+    let json = args.json;
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(status) => {
+            match json {
+                true => eprintln!("{}", status.to_json()),
+                false => eprintln!("{status}"),
+            }
+            ExitCode::from(status.exit_code())
+        }
+    }
+}
 
-    let app = second_brain::app::App { config, command };
-    app.run()?;
+fn run(args: Args) -> Result<(), Status> {
+    let command = match args.command.unwrap_or_default() {
+        Command::Vault(command) => command,
+        // commands that have to work without a valid config
+        Command::Completions { shell } => {
+            shell.write(&mut std::io::stdout())?;
+            return Ok(());
+        }
+        Command::Config {
+            command: ConfigCommands::Path,
+        } => {
+            let config_file = ConfigFile::try_from_env(CONFIG_ENV)?;
+            match args.json {
+                true => println!("{}", config_file.to_json()?),
+                false => println!("{config_file}"),
+            }
+            return Ok(());
+        }
+        Command::Config {
+            command: ConfigCommands::Show { toml },
+        } => {
+            let app = try_app(args.dry_run, args.json)?;
+            return Ok(app.show_config(toml)?);
+        }
+    };
+    try_app(args.dry_run, args.json)?.run(&command)
+}
 
-    Ok(())
+/// Attempts to load the vault configuration and the clock the app runs with.
+fn try_app(dry_run: bool, json: bool) -> Result<App, Status> {
+    let config_file = ConfigFile::try_from_env(CONFIG_ENV)?.try_build()?;
+    let config = AppConfig::try_from(config_file)?;
+    let options = RunOptions::default()
+        .with_dry_run(dry_run)
+        .with_json(json)
+        // without a state dir only the commands reading the history fail
+        .with_history(History::try_from_env()?);
+    let clock = try_clock_from_env(NOW_ENV, config.get_time_config())?;
+    Ok(App {
+        config,
+        clock,
+        options,
+    })
 }
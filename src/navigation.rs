@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Days, FixedOffset};
+use strum_macros::Display;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{Direction, try_list_children};
+}
+
+/// Direction to move in from an existing periodical note.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Direction {
+    /// The following note of the same periodical.
+    Next,
+    /// The preceding note of the same periodical.
+    Prev,
+    /// The note of the next larger periodical that contains the note.
+    Up,
+}
+
+impl Direction {
+    /// Finds the periodical and date of the note next to the passed in note.
+    pub fn try_navigate(
+        &self,
+        config: &AppConfig,
+        path: &Path,
+    ) -> Result<(Periodical, DateTime<FixedOffset>), RuntimeError> {
+        let (period, date) = try_parse(config, path)?;
+        let date = config.at_midnight(date);
+        let target = match self {
+            Direction::Next => period.get_next(date, 1).map(|date| (period, date)),
+            Direction::Prev => period.get_prev(date, 1).map(|date| (period, date)),
+            Direction::Up => match period.parent() {
+                Some(parent) => Some((parent, date)),
+                None => return Err(RuntimeError::NoParent(period)),
+            },
+        };
+        target.ok_or(RuntimeError::DateOutOfRange)
+    }
+}
+
+/// Lists the absolute paths of the notes one periodical smaller,
+/// that fall into the period of the passed in note.
+/// Weeks are listed for every month they overlap with.
+pub fn try_list_children(config: &AppConfig, path: &Path) -> Result<Vec<PathBuf>, RuntimeError> {
+    let (period, date) = try_parse(config, path)?;
+    let child = period.child().ok_or(RuntimeError::NoChildren(period))?;
+    let week_start = config.get_week_start();
+    let end = period.end_of(date, week_start);
+
+    let mut children = Vec::<PathBuf>::new();
+    let mut day = date;
+    while day <= end {
+        let path = config.try_format_absolute_note_path(child, config.at_midnight(day))?;
+        if children.last() != Some(&path) {
+            children.push(path);
+        }
+        day = day
+            .checked_add_days(Days::new(1))
+            .ok_or(RuntimeError::DateOutOfRange)?;
+    }
+    Ok(children)
+}
+
+/// Parses a user passed in path into the periodical and date of its note.
+fn try_parse(
+    config: &AppConfig,
+    path: &Path,
+) -> Result<(Periodical, chrono::NaiveDate), RuntimeError> {
+    let path = config.try_resolve_note_path(path)?;
+    config
+        .try_parse_note_path(&path)?
+        .ok_or(RuntimeError::NotPeriodical(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_config::TomlConfig;
+
+    fn config(vault: &Path) -> anyhow::Result<AppConfig> {
+        let s = format!(
+            "[vault]\ndir = {vault:?}\n[periodical.day]\ndir = \"day\"\n[periodical.week]\nfmt = \"{{start}}\""
+        );
+        Ok(AppConfig::try_from(toml::from_str::<TomlConfig>(&s)?)?)
+    }
+
+    #[test]
+    fn test_navigate() -> anyhow::Result<()> {
+        let desc = "Test navigating from existing notes";
+        let vault = tempfile::tempdir()?;
+        let config = config(vault.path())?;
+        let test_cases = [
+            ("day/2025-12-31.md", Direction::Next, "day/2026-01-01.md"),
+            ("day/2025-12-31.md", Direction::Prev, "day/2025-12-30.md"),
+            ("day/2025-12-31.md", Direction::Up, "2025-12-29.md"),
+            ("2025-12-29.md", Direction::Next, "2026-01-05.md"),
+            ("2025-12-29.md", Direction::Up, "2025-12.md"),
+            ("2025-12.md", Direction::Up, "2025.md"),
+        ];
+        test_cases.iter().try_for_each(|(from, direction, want)| {
+            let (period, date) = direction.try_navigate(&config, Path::new(from))?;
+            let got = config.try_format_absolute_note_path(period, date)?;
+
+            assert_eq!(vault.path().join(want), got, "{desc}: {direction} {from}");
+            anyhow::Ok(())
+        })
+    }
+
+    #[test]
+    fn test_navigate_invalid() -> anyhow::Result<()> {
+        let desc = "Test navigating to notes that can't exist";
+        let vault = tempfile::tempdir()?;
+        let config = config(vault.path())?;
+        let test_cases = [
+            ("2025.md", Direction::Up),
+            ("not-a-date.md", Direction::Next),
+        ];
+        test_cases.iter().for_each(|(from, direction)| {
+            let got = direction.try_navigate(&config, Path::new(from));
+            assert!(got.is_err(), "{desc}: {direction} {from}");
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_children() -> anyhow::Result<()> {
+        let desc = "Test listing the notes one periodical smaller";
+        let vault = tempfile::tempdir()?;
+        let config = config(vault.path())?;
+        let test_cases = [
+            ("2025-12-29.md", 7, "day/2025-12-29.md", "day/2026-01-04.md"),
+            ("2025-12.md", 5, "2025-12-01.md", "2025-12-29.md"),
+            ("2025.md", 12, "2025-01.md", "2025-12.md"),
+        ];
+        test_cases.iter().try_for_each(|(from, len, first, last)| {
+            let got = try_list_children(&config, Path::new(from))?;
+
+            assert_eq!(*len, got.len(), "{desc}: {from}");
+            assert_eq!(
+                Some(&vault.path().join(first)),
+                got.first(),
+                "{desc}: {from}"
+            );
+            assert_eq!(Some(&vault.path().join(last)), got.last(), "{desc}: {from}");
+            anyhow::Ok(())
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{NoteResolver, NoteWriter};
+}
+
+/// Turns periodicals and dates into note paths, and note paths back
+/// into their periodical and date.
+#[derive(Debug, Clone, Copy)]
+pub struct NoteResolver<'a> {
+    config: &'a AppConfig,
+}
+
+impl<'a> NoteResolver<'a> {
+    pub fn new(config: &'a AppConfig) -> Self {
+        Self { config }
+    }
+    /// Returns the clock's current date, shifted by the configured start of day.
+    pub fn today(&self, clock: &dyn Clock) -> NaiveDate {
+        self.config.now(clock).date_naive()
+    }
+    /// Returns the file name, without extension, of the note
+    /// of the period the date falls in.
+    pub fn note_name(&self, period: Periodical, date: NaiveDate) -> String {
+        self.config
+            .format_date(period, self.config.at_midnight(date))
+    }
+    /// Attempts to get the absolute path of the note of the period
+    /// the date falls in.
+    pub fn resolve(&self, period: Periodical, date: NaiveDate) -> Result<PathBuf, RuntimeError> {
+        self.config
+            .try_format_absolute_note_path(period, self.config.at_midnight(date))
+    }
+    /// Attempts to get the periodical and start date of the note at the path.
+    /// Relative paths that don't exist are resolved from the vault root.
+    /// Returns None if the path isn't a periodical note.
+    pub fn parse(&self, path: &Path) -> Result<Option<(Periodical, NaiveDate)>, RuntimeError> {
+        let path = self.config.try_resolve_note_path(path)?;
+        self.config.try_parse_note_path(&path)
+    }
+}
+
+/// Renders, writes and opens periodical notes.
+#[derive(Debug, Clone, Copy)]
+pub struct NoteWriter<'a> {
+    config: &'a AppConfig,
+    options: &'a RunOptions,
+}
+
+impl<'a> NoteWriter<'a> {
+    pub fn new(config: &'a AppConfig, options: &'a RunOptions) -> Self {
+        Self { config, options }
+    }
+    /// Attempts to render the contents a new note of the period
+    /// the date falls in would be written with.
+    pub fn render(&self, period: Periodical, date: NaiveDate) -> Result<String, Status> {
+        let contents = period.try_render(self.config, self.config.at_midnight(date))?;
+        Ok(String::from_utf8_lossy(&contents).into_owned())
+    }
+    /// Writes the note of the period the date falls in if it doesn't exist.
+    /// Returns the note's absolute path.
+    pub fn write(&self, period: Periodical, date: NaiveDate) -> Result<PathBuf, Status> {
+        period.try_create(self.config, self.options, self.config.at_midnight(date))
+    }
+    /// Writes the note of the period the date falls in if it doesn't exist,
+    /// then opens it in the editor.
+    /// The clock's time is recorded in the history.
+    pub fn open(
+        &self,
+        period: Periodical,
+        date: NaiveDate,
+        clock: &dyn Clock,
+    ) -> Result<(), Status> {
+        let date = self.config.at_midnight(date);
+        period.open_at(self.config, self.options, date, clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolver() -> anyhow::Result<()> {
+        let desc = "Test resolving note paths both ways";
+        let vault = tempfile::tempdir()?;
+        let config = AppConfig::builder(vault.path())
+            .dir(Periodical::Day, "day")
+            .try_build()?;
+        let resolver = NoteResolver::new(&config);
+        let date = NaiveDate::from_ymd_opt(2025, 12, 30).unwrap();
+
+        let path = resolver.resolve(Periodical::Week, date)?;
+        assert_eq!(vault.path().join("2026-W01.md"), path, "{desc}: resolve");
+        assert_eq!(
+            "2026-W01",
+            resolver.note_name(Periodical::Week, date),
+            "{desc}"
+        );
+
+        let monday = NaiveDate::from_ymd_opt(2025, 12, 29).unwrap();
+        let got = resolver.parse(Path::new("2026-W01.md"))?;
+        assert_eq!(Some((Periodical::Week, monday)), got, "{desc}: parse");
+        let got = resolver.parse(Path::new("day/2025-12-30.md"))?;
+        assert_eq!(Some((Periodical::Day, date)), got, "{desc}: parse day");
+        Ok(())
+    }
+
+    #[test]
+    fn test_writer() -> anyhow::Result<()> {
+        let desc = "Test writing notes without a config file";
+        let vault = tempfile::tempdir()?;
+        let config = AppConfig::builder(vault.path()).try_build()?;
+        let options = RunOptions::default();
+        let writer = NoteWriter::new(&config, &options);
+        let date = NaiveDate::from_ymd_opt(2025, 12, 30).unwrap();
+
+        let want = writer.render(Periodical::Month, date)?;
+        assert_eq!("[[2025-11]] - [[2026-01]]\n\n", want, "{desc}: render");
+
+        let path = writer.write(Periodical::Month, date)?;
+        assert_eq!(vault.path().join("2025-12.md"), path, "{desc}: path");
+        assert_eq!(want, std::fs::read_to_string(path)?, "{desc}: contents");
+        Ok(())
+    }
+}
@@ -1,11 +1,14 @@
-use std::{io::Write, path::Path};
+use std::{
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
 
-use chrono::{DateTime, Days, Local, Months};
-use clap::Parser;
-use serde::Deserialize;
-use strum_macros::{Display, EnumString, VariantNames};
+use chrono::{DateTime, Datelike, Days, FixedOffset, Months, NaiveDate};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString, VariantNames};
 
-use crate::prelude::*;
+use crate::{prelude::*, rollup::try_render_rollup};
 
 pub mod prelude {
     pub use super::Periodical;
@@ -19,10 +22,14 @@ pub mod prelude {
     Copy,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     Deserialize,
+    Serialize,
+    EnumIter,
     EnumString,
-    Parser,
+    ValueEnum,
     VariantNames,
 )]
 #[strum(serialize_all = "kebab-case")]
@@ -36,50 +43,185 @@ pub enum Periodical {
 }
 
 impl Periodical {
-    pub fn open(&self, config: &AppConfig) -> Result<(), Status> {
-        let date = Local::now();
+    /// Returns the file name format used when none is configured.
+    pub fn default_fmt(&self) -> &'static str {
+        match self {
+            Periodical::Day => DEFAULT_DAY,
+            Periodical::Week => DEFAULT_WEEK,
+            Periodical::Month => DEFAULT_MONTH,
+            Periodical::Year => DEFAULT_YEAR,
+        }
+    }
+    /// Returns the first day of the period the date falls in.
+    /// Only weeks depend on the passed in week start.
+    pub fn start_of(&self, date: NaiveDate, week_start: WeekStart) -> NaiveDate {
+        let start = match self {
+            Periodical::Day => Some(date),
+            Periodical::Week => Some(week_start.start_of(date)),
+            Periodical::Month => date.with_day(1),
+            Periodical::Year => date.with_ordinal(1),
+        };
+        start.unwrap_or(date)
+    }
+    /// Returns the last day of the period the date falls in.
+    /// Only weeks depend on the passed in week start.
+    pub fn end_of(&self, date: NaiveDate, week_start: WeekStart) -> NaiveDate {
+        let start = self.start_of(date, week_start);
+        let next = match self {
+            Periodical::Day => start.checked_add_days(Days::new(1)),
+            Periodical::Week => start.checked_add_days(Days::new(7)),
+            Periodical::Month => start.checked_add_months(Months::new(1)),
+            Periodical::Year => start.checked_add_months(Months::new(12)),
+        };
+        next.and_then(|next| next.pred_opt()).unwrap_or(start)
+    }
+    /// Returns the next larger periodical, if any.
+    pub fn parent(&self) -> Option<Periodical> {
+        match self {
+            Periodical::Day => Some(Periodical::Week),
+            Periodical::Week => Some(Periodical::Month),
+            Periodical::Month => Some(Periodical::Year),
+            Periodical::Year => None,
+        }
+    }
+    /// Returns the next smaller periodical, if any.
+    pub fn child(&self) -> Option<Periodical> {
+        match self {
+            Periodical::Day => None,
+            Periodical::Week => Some(Periodical::Day),
+            Periodical::Month => Some(Periodical::Week),
+            Periodical::Year => Some(Periodical::Month),
+        }
+    }
+    /// Opens the clock's current note in the editor.
+    pub fn open(
+        &self,
+        config: &AppConfig,
+        options: &RunOptions,
+        clock: &dyn Clock,
+    ) -> Result<(), Status> {
+        self.open_at(config, options, config.now(clock), clock)
+    }
+    /// Opens the note of the period the date falls in, runs the post-edit hook,
+    /// then commits it if git auto commits are enabled.
+    /// The note is recorded in the history, if any, at the clock's time
+    /// before the editor is launched.
+    /// The editor isn't launched on dry runs.
+    pub fn open_at(
+        &self,
+        config: &AppConfig,
+        options: &RunOptions,
+        date: DateTime<FixedOffset>,
+        clock: &dyn Clock,
+    ) -> Result<(), Status> {
+        let path = self.try_create(config, options, date)?;
+        if options.is_dry_run() {
+            DryRunReport::open_note(&path).print(options)?;
+            return Ok(());
+        }
+        if let Some(history) = options.get_history() {
+            let start = self.start_of(date.date_naive(), config.get_week_start());
+            let entry = HistoryEntry::new(clock.now(), *self, start, &path);
+            // an unwritable history shouldn't keep the note from opening
+            if let Err(e) = history.try_record(entry) {
+                eprintln!("Couldn't record {} in the history: {e}", path.display());
+            }
+        }
+        open_in_editor(config, &path)?;
+        config
+            .get_hook_config()
+            .try_run(Hook::PostEdit, config, *self, date, &path)?;
+        if config.get_git_config().get_auto_commit() {
+            try_commit_note(config, *self, date, &path)?;
+        }
+        Ok(())
+    }
+    /// Writes the note of the period the date falls in if it doesn't exist,
+    /// running the pre-create and post-create hooks around the write.
+    /// The post-create hook is skipped if another process created the note first.
+    /// On dry runs the write is only reported.
+    /// Returns the note's absolute path.
+    pub fn try_create(
+        &self,
+        config: &AppConfig,
+        options: &RunOptions,
+        date: DateTime<FixedOffset>,
+    ) -> Result<PathBuf, Status> {
         let path = config.try_format_absolute_note_path(*self, date)?;
         // write file if it doesn't exist
         if !path.exists() {
-            self.write(config, &path, date)?;
+            // hooks could change the vault, so they're skipped on dry runs
+            let hooks = (!options.is_dry_run()).then(|| config.get_hook_config());
+            if let Some(hooks) = hooks {
+                hooks.try_run(Hook::PreCreate, config, *self, date, &path)?;
+            }
+            let created = self.write(config, options, &path, date)?;
+            if let Some(hooks) = hooks.filter(|_| created) {
+                hooks.try_run(Hook::PostCreate, config, *self, date, &path)?;
+            }
         }
-        // open file in editor
-        let editor = std::env::var("EDITOR").unwrap_or("nvim".into());
-        std::env::set_current_dir(config.get_vault_root()).map_err(RuntimeError::Io)?;
-        std::process::Command::new(editor)
-            .arg(path)
-            .status()
-            .map_err(RuntimeError::Io)?;
-
-        Ok(())
+        Ok(path)
     }
-    fn write(&self, config: &AppConfig, path: &Path, date: DateTime<Local>) -> Result<(), Status> {
+    /// Attempts to write the note's contents to the path.
+    /// Returns whether the note was created, false on dry runs
+    /// or if the note already exists.
+    fn write(
+        &self,
+        config: &AppConfig,
+        options: &RunOptions,
+        path: &Path,
+        date: DateTime<FixedOffset>,
+    ) -> Result<bool, Status> {
+        let contents = self.try_render(config, date)?;
+        if options.is_dry_run() {
+            DryRunReport::create_note(path, &contents).print(options)?;
+            return Ok(false);
+        }
+        // create any necessary parent dirs
+        let parent_path = path.parent().unwrap_or(config.get_vault_root());
+        std::fs::create_dir_all(parent_path).map_err(RuntimeError::Io)?;
+        // write to a temp file next to the note, so the note is never seen half written
+        let mut f = tempfile::NamedTempFile::new_in(parent_path).map_err(RuntimeError::Io)?;
+        f.write_all(&contents).map_err(RuntimeError::Io)?;
+        f.as_file().sync_all().map_err(RuntimeError::Io)?;
+        // another process creating the note first isn't an error, the existing note is used
+        match f.persist_noclobber(path) {
+            Ok(_) => Ok(true),
+            Err(e) if e.error.kind() == ErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(RuntimeError::Io(e.error).into()),
+        }
+    }
+    /// Renders the contents of a new note: the heading, followed by the
+    /// roll-up links and the configured template, if any.
+    pub(crate) fn try_render(
+        &self,
+        config: &AppConfig,
+        date: DateTime<FixedOffset>,
+    ) -> Result<Vec<u8>, Status> {
         let mut contents = Vec::<u8>::new();
         if let Some(heading) = self.try_format_heading(config, date) {
             contents.append(&mut heading.into());
             contents.append(&mut "\n\n".to_string().into());
         }
+        if let Some(rollup) = try_render_rollup(config, *self, date)? {
+            contents.append(&mut rollup.into());
+            contents.append(&mut "\n\n".to_string().into());
+        }
         if let Some(template_path) = config.try_format_absolute_template_path(*self)? {
             // validate template before reading
             if !template_path.is_file() {
-                return Err(ConfigError::InvalidFile(template_path).into());
+                return Err(ConfigError::InvalidFile(vec![template_path]).into());
             }
             let mut template = std::fs::read(template_path).map_err(RuntimeError::Io)?;
             contents.append(&mut template);
         }
-        // create any necessary parent dirs
-        if let Some(parent_path) = path.parent() {
-            std::fs::create_dir_all(parent_path).map_err(RuntimeError::Io)?;
-        }
-        // create file
-        let mut f = std::fs::File::create_new(path).map_err(RuntimeError::Io)?;
-        // write any template contents
-        if !contents.is_empty() {
-            f.write(&contents).map_err(RuntimeError::Io)?;
-        }
-        Ok(())
+        Ok(contents)
     }
-    fn try_format_heading(&self, config: &AppConfig, date: DateTime<Local>) -> Option<String> {
+    fn try_format_heading(
+        &self,
+        config: &AppConfig,
+        date: DateTime<FixedOffset>,
+    ) -> Option<String> {
         let prev = config.format_date(*self, self.get_prev(date, 1)?);
         let next = config.format_date(*self, self.get_next(date, 1)?);
 
@@ -88,7 +230,11 @@ impl Periodical {
     /// Given a start date and an interval of Periodcals expressed as an uint,
     /// will calculate the next interval date in time
     /// with the correct formatting.
-    fn get_next(&self, date: DateTime<Local>, interval: u32) -> Option<DateTime<Local>> {
+    pub fn get_next(
+        &self,
+        date: DateTime<FixedOffset>,
+        interval: u32,
+    ) -> Option<DateTime<FixedOffset>> {
         match self {
             Periodical::Day => date.checked_add_days(Days::new(u64::from(interval))),
             Periodical::Week => date.checked_add_days(Days::new(u64::from(interval * 7))),
@@ -97,9 +243,13 @@ impl Periodical {
         }
     }
     /// Given a start date and an interval of Periodcals expressed as an uint,
-    /// will calculate the next interval date in time
+    /// will calculate the previous interval date in time
     /// with the correct formatting.
-    fn get_prev(&self, date: DateTime<Local>, interval: u32) -> Option<DateTime<Local>> {
+    pub fn get_prev(
+        &self,
+        date: DateTime<FixedOffset>,
+        interval: u32,
+    ) -> Option<DateTime<FixedOffset>> {
         match self {
             Periodical::Day => date.checked_sub_days(Days::new(u64::from(interval))),
             Periodical::Week => date.checked_sub_days(Days::new(u64::from(interval * 7))),
@@ -111,14 +261,17 @@ impl Periodical {
 
 #[cfg(test)]
 mod test {
-    use chrono::TimeZone;
+    use chrono::{Local, TimeZone};
 
-    use super::super::periodic_config::PeriodConfig;
+    use super::super::{app_config::TomlConfig, periodic_config::PeriodConfig};
     use super::*;
 
     #[test]
     fn test_get_next() {
-        let date = Local.with_ymd_and_hms(2025, 12, 30, 0, 0, 0).unwrap();
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
         let config = PeriodConfig::default();
 
         let test_cases = [
@@ -131,7 +284,7 @@ mod test {
         test_cases.into_iter().for_each(|(period, interval, want)| {
             let got = period
                 .get_next(date, interval)
-                .map(|f| config.format(period, f));
+                .map(|f| config.format(period, f, config.get_week_start()));
 
             assert_eq!(Some(want.to_string()), got)
         });
@@ -139,7 +292,10 @@ mod test {
 
     #[test]
     fn test_get_prev() {
-        let date = Local.with_ymd_and_hms(2025, 12, 30, 0, 0, 0).unwrap();
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
         let config = PeriodConfig::default();
 
         let test_cases = [
@@ -152,15 +308,65 @@ mod test {
         test_cases.into_iter().for_each(|(period, interval, want)| {
             let got = period
                 .get_prev(date, interval)
-                .map(|f| config.format(period, f));
+                .map(|f| config.format(period, f, config.get_week_start()));
 
             assert_eq!(Some(want.to_string()), got)
         });
     }
+    #[test]
+    fn test_period_bounds() {
+        let desc = "Test start and end of the period a date falls in";
+        let date = NaiveDate::from_ymd_opt(2025, 12, 30).unwrap();
+        let test_cases = [
+            (
+                Periodical::Day,
+                WeekStart::Monday,
+                "2025-12-30",
+                "2025-12-30",
+            ),
+            (
+                Periodical::Week,
+                WeekStart::Monday,
+                "2025-12-29",
+                "2026-01-04",
+            ),
+            (
+                Periodical::Week,
+                WeekStart::Sunday,
+                "2025-12-28",
+                "2026-01-03",
+            ),
+            (
+                Periodical::Month,
+                WeekStart::Monday,
+                "2025-12-01",
+                "2025-12-31",
+            ),
+            (
+                Periodical::Year,
+                WeekStart::Monday,
+                "2025-01-01",
+                "2025-12-31",
+            ),
+        ];
+
+        test_cases
+            .into_iter()
+            .for_each(|(period, week_start, start, end)| {
+                let got_start = period.start_of(date, week_start).to_string();
+                let got_end = period.end_of(date, week_start).to_string();
+                assert_eq!(start, got_start, "{desc}: start {period} {week_start}");
+                assert_eq!(end, got_end, "{desc}: end {period} {week_start}");
+            });
+    }
+
     #[test]
     fn test_headings() {
         let desc = "Test template heading genereation";
-        let date = Local.with_ymd_and_hms(2025, 12, 30, 0, 0, 0).unwrap();
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
         let config = AppConfig::default();
 
         let test_cases = [
@@ -175,4 +381,117 @@ mod test {
             assert_eq!(Some(want.into()), got, "{desc}: {period}")
         });
     }
+
+    #[test]
+    fn test_week_headings() -> anyhow::Result<()> {
+        let desc = "Test week heading links follow the configured numbering";
+        // a Sunday, which starts the second US week of 2026
+        let date = Local
+            .with_ymd_and_hms(2026, 1, 4, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
+        let test_cases = [
+            ("", "[[2025-W52]] - [[2026-W02]]", "Case default iso"),
+            (
+                "week_start = \"sunday\"\nweek_numbering = \"us\"",
+                "[[2026-W01]] - [[2026-W03]]",
+                "Case sunday us",
+            ),
+            (
+                "fmt = \"{start} to {end}\"",
+                "[[2025-12-22 to 2025-12-28]] - [[2026-01-05 to 2026-01-11]]",
+                "Case start and end tokens",
+            ),
+        ];
+        test_cases.iter().try_for_each(|(week, want, case)| {
+            let s = format!("[vault]\ndir = \".\"\n[periodical.week]\n{week}");
+            let config = AppConfig::try_from(toml::from_str::<TomlConfig>(&s)?)?;
+            let got = Periodical::Week.try_format_heading(&config, date);
+
+            assert_eq!(Some(want.to_string()), got, "{desc}: {case}");
+            anyhow::Ok(())
+        })
+    }
+
+    #[test]
+    fn test_dry_run() -> anyhow::Result<()> {
+        let desc = "Test dry runs report the note without writing it";
+        let vault = tempfile::tempdir()?;
+        let s = format!(
+            "[vault]\ndir = {:?}\n[periodical.day]\ndir = \"journal/day\"",
+            vault.path()
+        );
+        let config = AppConfig::try_from(toml::from_str::<TomlConfig>(&s)?)?;
+        let options = RunOptions::default().with_dry_run(true);
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 12, 30).unwrap());
+
+        let path = Periodical::Day.try_create(&config, &options, date)?;
+        assert!(
+            !vault.path().join("journal").exists(),
+            "{desc}: nothing written"
+        );
+
+        assert_eq!(
+            vault.path().join("journal/day/2025-12-30.md"),
+            path,
+            "{desc}: path"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concurrent_create() -> anyhow::Result<()> {
+        let desc = "Test racing writes of the same note all succeed";
+        let vault = tempfile::tempdir()?;
+        let s = format!("[vault]\ndir = {:?}", vault.path());
+        let config = AppConfig::try_from(toml::from_str::<TomlConfig>(&s)?)?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 12, 30).unwrap());
+
+        let paths = std::thread::scope(|scope| {
+            let handles = (0..8)
+                .map(|_| {
+                    scope
+                        .spawn(|| Periodical::Day.try_create(&config, &RunOptions::default(), date))
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("thread panicked"))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        let want = vault.path().join("2025-12-30.md");
+        assert!(paths.iter().all(|path| *path == want), "{desc}: paths");
+        assert_eq!(
+            1,
+            std::fs::read_dir(vault.path())?.count(),
+            "{desc}: no temp files left"
+        );
+        assert_eq!(
+            "[[2025-12-29]] - [[2025-12-31]]\n\n",
+            std::fs::read_to_string(&want)?,
+            "{desc}: contents"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_existing() -> anyhow::Result<()> {
+        let desc = "Test existing notes are left untouched";
+        let vault = tempfile::tempdir()?;
+        let s = format!("[vault]\ndir = {:?}", vault.path());
+        let config = AppConfig::try_from(toml::from_str::<TomlConfig>(&s)?)?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 12, 30).unwrap());
+        let path = vault.path().join("2025-12-30.md");
+        std::fs::write(&path, "written by obsidian")?;
+
+        // skips the existence check, as if the note was created mid write
+        let created = Periodical::Day.write(&config, &RunOptions::default(), &path, date)?;
+        assert!(!created, "{desc}: reported as created");
+        assert_eq!(
+            "written by obsidian",
+            std::fs::read_to_string(&path)?,
+            "{desc}"
+        );
+        Ok(())
+    }
 }
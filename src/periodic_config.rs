@@ -1,13 +1,24 @@
-use chrono::{DateTime, Datelike, Local};
+use chrono::{DateTime, Days, FixedOffset, NaiveDate, NaiveTime};
 use serde::Deserialize;
 
 use crate::prelude::*;
 
+/// Token replaced by the week of the month in file name formats.
+const WEEK_OF_MONTH: &str = "{week_of_month}";
+/// Token replaced by the first day of the period.
+/// Takes an optional date format, e.g. `{start:%d %b}`.
+const START: &str = "start";
+/// Token replaced by the last day of the period.
+/// Takes an optional date format, e.g. `{end:%d %b}`.
+const END: &str = "end";
+
 #[derive(Debug, Default, Deserialize, PartialEq)]
 pub struct PeriodConfig {
-    dir: Option<String>,
-    template: Option<String>,
-    fmt: Option<String>,
+    pub(crate) dir: Option<String>,
+    pub(crate) template: Option<String>,
+    pub(crate) fmt: Option<String>,
+    pub(crate) week_start: Option<WeekStart>,
+    pub(crate) week_numbering: Option<WeekNumbering>,
 }
 
 impl PeriodConfig {
@@ -21,45 +32,134 @@ impl PeriodConfig {
     pub fn get_template_file(&self) -> Option<&str> {
         Some(self.template.as_ref()?.as_str())
     }
+    /// Getter that returns the configured date format string
+    /// for the periodical note file.
+    /// Returns a default format if not configured.
+    pub fn get_fmt(&self, period: Periodical) -> &str {
+        self.fmt.as_deref().unwrap_or(period.default_fmt())
+    }
+    /// Getter for the day weeks start on.
+    /// Returns Monday if not configured.
+    pub fn get_week_start(&self) -> WeekStart {
+        self.week_start.unwrap_or_default()
+    }
+    /// Getter for the scheme weeks are numbered with.
+    /// Returns ISO numbering if not configured.
+    pub fn get_week_numbering(&self) -> WeekNumbering {
+        self.week_numbering.unwrap_or_default()
+    }
     /// Attempts to get the configured file name associated with
     /// this Periodical.
+    /// The date is normalized to the start of its period before formatting,
+    /// so every date in the period has the same file name.
+    /// Weeks, and `{week_of_month}`, start on the passed in vault wide week start.
     /// Returns a default format if not configured.
-    pub fn format(&self, period: Periodical, date: DateTime<Local>) -> String {
-        let fmt = self.fmt.as_deref().unwrap_or(match period {
-            Periodical::Day => DEFAULT_DAY,
-            Periodical::Week => DEFAULT_WEEK,
-            Periodical::Month => DEFAULT_MONTH,
-            Periodical::Year => DEFAULT_YEAR,
-        });
-        if matches!(period, Periodical::Week) {
-            let year = date.iso_week().year();
-            let year = match year.abs() < 10 {
-                true => format!("0{year}"),
-                false => year.to_string(),
-            };
-            let week = date.iso_week().week();
-            let week = match week < 10 {
-                true => format!("0{week}"),
-                false => week.to_string(),
-            };
-            let res = fmt.replace("%Y", &year);
-            let res = res.replace("%V", &week);
-            return res;
+    pub fn format(
+        &self,
+        period: Periodical,
+        date: DateTime<FixedOffset>,
+        week_start: WeekStart,
+    ) -> String {
+        let start = period.start_of(date.date_naive(), week_start);
+        let end = period.end_of(date.date_naive(), week_start);
+
+        let mut fmt = self
+            .get_fmt(period)
+            .replace(WEEK_OF_MONTH, &week_start.week_of_month(start).to_string());
+        fmt = replace_date_token(&fmt, START, start);
+        fmt = replace_date_token(&fmt, END, end);
+        // week numbers replace the year and week with the configured numbering,
+        // a week formatted as a plain date keeps its calendar year
+        if matches!(period, Periodical::Week) && fmt.contains("%V") {
+            let (year, week) = self.get_week_numbering().number(start, week_start);
+            fmt = fmt
+                .replace("%Y", &format!("{year:02}"))
+                .replace("%V", &format!("{week:02}"));
         }
-        date.format(fmt).to_string()
+        let start = start
+            .and_time(NaiveTime::MIN)
+            .and_local_timezone(*date.offset())
+            .single()
+            .unwrap_or(date);
+        start.format(&fmt).to_string()
+    }
+    /// Inverse of `format`: attempts to find the period a file name
+    /// was formatted from.
+    /// Returns the first day of the period, or None if the name
+    /// doesn't match the configured format.
+    pub fn parse(
+        &self,
+        period: Periodical,
+        name: &str,
+        week_start: WeekStart,
+    ) -> Option<NaiveDate> {
+        let fmt = self.get_fmt(period);
+        let fields = parse_date_fields(fmt, name)?;
+
+        let date = match (period, fields.year, fields.week) {
+            (Periodical::Week, Some(year), Some(week)) if fmt.contains("%V") => {
+                // walk the weeks around the year to find the numbered one
+                let first = NaiveDate::from_yo_opt(year, 1)?.checked_sub_days(Days::new(14))?;
+                (0..60)
+                    .filter_map(|i| first.checked_add_days(Days::new(i * 7)))
+                    .map(|date| week_start.start_of(date))
+                    .find(|date| {
+                        self.get_week_numbering().number(*date, week_start) == (year, week)
+                    })?
+            }
+            _ => fields.to_date()?,
+        };
+        // formatting again validates any fields that couldn't be captured
+        let start = period.start_of(date, week_start);
+        let formatted = self.format(
+            period,
+            start.and_time(NaiveTime::MIN).and_utc().fixed_offset(),
+            week_start,
+        );
+        (formatted == name).then_some(start)
     }
 }
 
+/// Replaces every `{name}` or `{name:fmt}` token with the formatted date.
+/// Tokens without a format use the default day format.
+fn replace_date_token(fmt: &str, name: &str, date: NaiveDate) -> String {
+    let open = format!("{{{name}");
+    let mut res = String::new();
+    let mut rest = fmt;
+
+    while let Some(i) = rest.find(&open) {
+        res.push_str(&rest[..i]);
+        let token = &rest[i + open.len()..];
+        let (date_fmt, len) = match token.split_once('}') {
+            Some(("", _)) => (DEFAULT_DAY, 1),
+            Some((inner, _)) if inner.starts_with(':') => (&inner[1..], inner.len() + 1),
+            // not a token, e.g. `{starting}`
+            _ => {
+                res.push_str(&open);
+                rest = token;
+                continue;
+            }
+        };
+        res.push_str(&date.format(date_fmt).to_string());
+        rest = &token[len..];
+    }
+    res.push_str(rest);
+    res
+}
+
 #[cfg(test)]
 mod tests {
-    use chrono::TimeZone;
+    use chrono::{Local, TimeZone};
 
     use super::*;
 
     #[test]
     fn test_default_formatter() {
         let desc = "Test default configs";
-        let date = Local.with_ymd_and_hms(2025, 12, 30, 0, 0, 0).unwrap();
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
         let config = PeriodConfig::default();
 
         let test_cases = [
@@ -70,7 +170,7 @@ mod tests {
         ];
 
         test_cases.into_iter().for_each(|(period, want)| {
-            let got = config.format(period, date);
+            let got = config.format(period, date, config.get_week_start());
             assert_eq!(want, got, "{desc}: {period}");
         });
     }
@@ -78,7 +178,10 @@ mod tests {
     #[test]
     fn test_mixed_configs_filename() {
         let desc = "Test a mix of configured and unconfigrued date formats";
-        let date = Local.with_ymd_and_hms(2025, 12, 30, 0, 0, 0).unwrap();
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
         let test_cases = [
             (Periodical::Day, "12-30-2025", "configured day"),
             (Periodical::Week, "01-2026", "configured week"),
@@ -104,8 +207,200 @@ mod tests {
             .into_iter()
             .zip(config)
             .for_each(|((period, want, case), config)| {
-                let got = config.format(period, date);
+                let got = config.format(period, date, config.get_week_start());
                 assert_eq!(want, got, "{desc}: {case} {period}");
             });
     }
+
+    #[test]
+    fn test_week_configs_filename() {
+        let desc = "Test configured week starts and numbering";
+        // a Sunday, which is in a different week depending on the week start
+        let date = Local
+            .with_ymd_and_hms(2026, 1, 4, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
+        let test_cases = [
+            (None, None, "%Y-W%V", "2026-W01", "default iso"),
+            (
+                Some(WeekStart::Sunday),
+                None,
+                "%Y-W%V",
+                "2026-W02",
+                "sunday iso",
+            ),
+            (
+                Some(WeekStart::Sunday),
+                Some(WeekNumbering::Us),
+                "%Y-W%V",
+                "2026-W02",
+                "sunday us",
+            ),
+            (
+                Some(WeekStart::Sunday),
+                Some(WeekNumbering::Simple),
+                "%Y-W%V",
+                "2026-W01",
+                "sunday simple",
+            ),
+            (
+                Some(WeekStart::Sunday),
+                None,
+                "%Y-%m-W{week_of_month}",
+                "2026-01-W2",
+                "week of month",
+            ),
+        ];
+        test_cases
+            .into_iter()
+            .for_each(|(week_start, week_numbering, fmt, want, case)| {
+                let config = PeriodConfig {
+                    fmt: Some(fmt.into()),
+                    week_start,
+                    week_numbering,
+                    ..Default::default()
+                };
+                let got = config.format(Periodical::Week, date, config.get_week_start());
+                assert_eq!(want, got, "{desc}: {case}");
+            });
+    }
+
+    #[test]
+    fn test_period_start_filename() {
+        let desc = "Test dates are formatted from the start of their period";
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 9, 0, 0)
+            .unwrap()
+            .fixed_offset();
+        let test_cases = [
+            (Periodical::Week, "%Y-%m-%d", "2025-12-29", "week as a date"),
+            (
+                Periodical::Month,
+                "%Y-%m-%d",
+                "2025-12-01",
+                "month as a date",
+            ),
+            (Periodical::Year, "%Y-%m-%d", "2025-01-01", "year as a date"),
+            (
+                Periodical::Day,
+                "%Y-%m-%d %H:%M",
+                "2025-12-30 00:00",
+                "day at midnight",
+            ),
+            (
+                Periodical::Week,
+                "{start} to {end}",
+                "2025-12-29 to 2026-01-04",
+                "start and end tokens",
+            ),
+            (
+                Periodical::Month,
+                "%B ({start:%d}-{end:%d})",
+                "December (01-31)",
+                "formatted tokens",
+            ),
+            (Periodical::Day, "{starting}", "{starting}", "not a token"),
+        ];
+        test_cases
+            .into_iter()
+            .for_each(|(period, fmt, want, case)| {
+                let config = PeriodConfig {
+                    fmt: Some(fmt.into()),
+                    ..Default::default()
+                };
+                let got = config.format(period, date, config.get_week_start());
+                assert_eq!(want, got, "{desc}: {case}");
+            });
+    }
+
+    #[test]
+    fn test_parse() {
+        let desc = "Test parsing file names back into the start of their period";
+        let test_cases = [
+            (
+                Periodical::Day,
+                None,
+                None,
+                "2025-12-30",
+                Some("2025-12-30"),
+            ),
+            (
+                Periodical::Day,
+                Some("%m-%d-%Y"),
+                None,
+                "12-30-2025",
+                Some("2025-12-30"),
+            ),
+            (Periodical::Week, None, None, "2026-W01", Some("2025-12-29")),
+            (
+                Periodical::Week,
+                None,
+                Some(WeekNumbering::Us),
+                "2026-W01",
+                Some("2025-12-29"),
+            ),
+            (
+                Periodical::Week,
+                Some("{start} to {end}"),
+                None,
+                "2025-12-29 to 2026-01-04",
+                Some("2025-12-29"),
+            ),
+            (
+                Periodical::Week,
+                Some("{start} to {end}"),
+                None,
+                "2025-12-30 to 2026-01-04",
+                None,
+            ),
+            (Periodical::Month, None, None, "2025-12", Some("2025-12-01")),
+            (
+                Periodical::Month,
+                Some("%B %Y"),
+                None,
+                "December 2025",
+                Some("2025-12-01"),
+            ),
+            (Periodical::Year, None, None, "2025", Some("2025-01-01")),
+            (Periodical::Day, None, None, "2025-02-30", None),
+            (Periodical::Day, None, None, "meeting notes", None),
+        ];
+        test_cases
+            .into_iter()
+            .for_each(|(period, fmt, week_numbering, name, want)| {
+                let config = PeriodConfig {
+                    fmt: fmt.map(|f| f.into()),
+                    week_numbering,
+                    ..Default::default()
+                };
+                let got = config
+                    .parse(period, name, config.get_week_start())
+                    .map(|d| d.to_string());
+                assert_eq!(want.map(|w| w.to_string()), got, "{desc}: {period} {name}");
+            });
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let desc = "Test every formatted week parses back to its start";
+        let config = PeriodConfig {
+            week_start: Some(WeekStart::Sunday),
+            week_numbering: Some(WeekNumbering::Simple),
+            ..Default::default()
+        };
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 1, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
+        (0..60).for_each(|i| {
+            let date = date + Days::new(i);
+            let name = config.format(Periodical::Week, date, config.get_week_start());
+            let want = Periodical::Week.start_of(date.date_naive(), WeekStart::Sunday);
+            assert_eq!(
+                Some(want),
+                config.parse(Periodical::Week, &name, WeekStart::Sunday),
+                "{desc}: {name}"
+            );
+        });
+    }
 }
@@ -0,0 +1,623 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::Renderer;
+}
+
+/// Matches the `> [!type] Title` line opening a callout,
+/// capturing the type and the optional title.
+static CALLOUT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^>\s*\[!([\w-]+)\][+-]?\s*(.*)$").unwrap());
+/// Extensions of attachments embedded as images.
+const IMAGE_EXTENSIONS: [&str; 7] = ["png", "jpg", "jpeg", "gif", "svg", "webp", "bmp"];
+/// How deep notes embedded in embedded notes are still transcluded,
+/// which also stops notes embedding each other.
+const MAX_EMBED_DEPTH: usize = 4;
+/// Styles of the elements markdown has no syntax for.
+const STYLE: &str = "\
+body { max-width: 48rem; margin: 2rem auto; padding: 0 1rem; font-family: sans-serif; line-height: 1.5; }
+.callout { border-left: 4px solid #448aff; background: #448aff1a; padding: 0.25rem 1rem; margin: 1rem 0; }
+.callout-title { font-weight: bold; }
+.embed { border-left: 2px solid #8888; padding-left: 1rem; margin: 1rem 0; }
+.unresolved { color: #888; }
+mark { background: #fff3a3; }
+img { max-width: 100%; }";
+
+/// Renders vault markdown to HTML, with Obsidian's wikilinks, embeds,
+/// callouts and highlights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Renderer {
+    root: PathBuf,
+    /// Notes and attachments by their lowercase vault relative path and file name.
+    /// Notes are keyed without their `.md` extension.
+    files: HashMap<String, PathBuf>,
+    /// Anchors that links to these notes, by lowercase name, point at instead of their files.
+    anchors: HashMap<String, String>,
+}
+
+impl Renderer {
+    /// Attempts to index every note and attachment in the vault,
+    /// so wikilinks can be resolved by path or file name.
+    pub fn try_new(config: &AppConfig) -> Result<Self, RuntimeError> {
+        let root = config.get_vault_root().to_path_buf();
+        let mut files = HashMap::new();
+        for path in list_files(&root)? {
+            let relative = path
+                .strip_prefix(&root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/")
+                .to_lowercase();
+            let relative = relative
+                .strip_suffix(".md")
+                .unwrap_or(&relative)
+                .to_string();
+            let name = relative.rsplit('/').next().unwrap_or_default().to_string();
+            files.insert(relative, path.clone());
+            // the first of several files sharing a name wins, like in Obsidian
+            files.entry(name).or_insert(path);
+        }
+        Ok(Self {
+            root,
+            files,
+            anchors: HashMap::new(),
+        })
+    }
+    /// Points links to the notes, by name, at the anchors instead of their files.
+    pub fn with_anchors(mut self, anchors: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.anchors = anchors
+            .into_iter()
+            .map(|(name, anchor)| (name.to_lowercase(), anchor))
+            .collect();
+        self
+    }
+    /// Renders markdown to an HTML fragment.
+    /// Links and images point at files relative to the vault root.
+    pub fn render(&self, markdown: &str) -> String {
+        self.render_at(markdown, 0, None)
+    }
+    /// Renders markdown to an HTML fragment that shares a document with
+    /// other notes, with heading ids prefixed by the note's anchor so they
+    /// stay unique. Links to the note's own headings point at the prefixed ids.
+    pub fn render_anchored(&self, markdown: &str, anchor: &str) -> String {
+        self.render_at(markdown, 0, Some(anchor))
+    }
+    /// Attempts to render the note, without its frontmatter or generated
+    /// navigation heading, as a standalone HTML document.
+    pub fn try_render_note(&self, path: &Path) -> Result<String, RuntimeError> {
+        let contents = std::fs::read_to_string(path)?;
+        let (_, body) = split_frontmatter(&contents);
+        let title = path.file_stem().unwrap_or_default().to_string_lossy();
+        Ok(Self::to_document(
+            &title,
+            &self.render(strip_navigation(body)),
+        ))
+    }
+    /// Wraps an HTML fragment in a standalone document.
+    pub fn to_document(title: &str, body: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+            escape(title)
+        )
+    }
+
+    fn render_at(&self, markdown: &str, depth: usize, prefix: Option<&str>) -> String {
+        let markdown = expand_callouts(markdown);
+        let options = Options::ENABLE_TABLES
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_WIKILINKS;
+        let mut events = Parser::new_ext(&markdown, options).collect::<Vec<_>>();
+        add_heading_ids(&mut events, prefix);
+
+        let mut rendered = vec![];
+        let mut events = events.into_iter().peekable();
+        let mut unresolved_link = false;
+        let mut in_code = false;
+        // index of the `<mark>` still waiting for its closing `==`
+        let mut open_mark = None;
+        // target and alias text of the embed being read
+        let mut embed: Option<(String, Option<String>)> = None;
+        while let Some(event) = events.next() {
+            if let Some((target, alias)) = &mut embed {
+                match event {
+                    Event::End(TagEnd::Image) => {
+                        let html = self.render_embed(target, alias.as_deref(), depth, prefix);
+                        // a note embedded on its own line replaces the paragraph
+                        if html.starts_with("<div")
+                            && rendered.last() == Some(&Event::Start(Tag::Paragraph))
+                            && events.peek() == Some(&Event::End(TagEnd::Paragraph))
+                        {
+                            rendered.pop();
+                            events.next();
+                            rendered.push(Event::Html(html.into()));
+                        } else {
+                            rendered.push(Event::InlineHtml(html.into()));
+                        }
+                        embed = None;
+                    }
+                    Event::Text(text) | Event::Code(text) => {
+                        if let Some(alias) = alias {
+                            alias.push_str(&text)
+                        }
+                    }
+                    _ => (),
+                }
+                continue;
+            }
+            if is_block_boundary(&event)
+                && let Some(i) = open_mark.take()
+            {
+                // never closed, so it wasn't a highlight
+                rendered[i] = Event::Text("==".into());
+            }
+            match event {
+                Event::Start(Tag::Link {
+                    link_type: LinkType::WikiLink { .. },
+                    dest_url,
+                    ..
+                }) => match self.resolve_link(&dest_url, prefix) {
+                    Some(href) => rendered.push(Event::Start(Tag::Link {
+                        link_type: LinkType::Inline,
+                        dest_url: href.into(),
+                        title: "".into(),
+                        id: "".into(),
+                    })),
+                    None => {
+                        unresolved_link = true;
+                        rendered.push(Event::InlineHtml("<a class=\"unresolved\">".into()));
+                    }
+                },
+                Event::End(TagEnd::Link) if unresolved_link => {
+                    unresolved_link = false;
+                    rendered.push(Event::InlineHtml("</a>".into()));
+                }
+                Event::Start(Tag::Image {
+                    link_type: LinkType::WikiLink { has_pothole },
+                    dest_url,
+                    ..
+                }) => embed = Some((dest_url.to_string(), has_pothole.then(String::new))),
+                Event::Start(Tag::CodeBlock(_)) => {
+                    in_code = true;
+                    rendered.push(event);
+                }
+                Event::End(TagEnd::CodeBlock) => {
+                    in_code = false;
+                    rendered.push(event);
+                }
+                Event::Text(text) if !in_code && text.contains("==") => {
+                    for (i, part) in text.split("==").enumerate() {
+                        if i > 0 {
+                            match open_mark.take() {
+                                Some(_) => rendered.push(Event::InlineHtml("</mark>".into())),
+                                None => {
+                                    open_mark = Some(rendered.len());
+                                    rendered.push(Event::InlineHtml("<mark>".into()));
+                                }
+                            }
+                        }
+                        if !part.is_empty() {
+                            rendered.push(Event::Text(part.to_string().into()));
+                        }
+                    }
+                }
+                event => rendered.push(event),
+            }
+        }
+        if let Some(i) = open_mark {
+            rendered[i] = Event::Text("==".into());
+        }
+
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, rendered.into_iter());
+        html
+    }
+
+    /// Resolves a wikilink's `target#heading` to the href it points at.
+    fn resolve_link(&self, dest: &str, prefix: Option<&str>) -> Option<String> {
+        let (target, heading) = split_dest(dest);
+        if target.is_empty() {
+            return Some(format!("#{}", heading_id(prefix, heading?)));
+        }
+        let name = target.rsplit('/').next().unwrap_or(target);
+        let name = name.strip_suffix(".md").unwrap_or(name).to_lowercase();
+        if let Some(anchor) = self.anchors.get(&name) {
+            return Some(match heading {
+                Some(heading) => format!("#{}", heading_id(Some(anchor), heading)),
+                None => format!("#{anchor}"),
+            });
+        }
+        let href = self.href(self.resolve(target)?);
+        Some(match heading {
+            Some(heading) => format!("{href}#{}", slugify(heading)),
+            None => href,
+        })
+    }
+
+    /// Renders an `![[embed]]`: images are shown, notes or their sections
+    /// transcluded, and other attachments linked.
+    fn render_embed(
+        &self,
+        dest: &str,
+        alias: Option<&str>,
+        depth: usize,
+        prefix: Option<&str>,
+    ) -> String {
+        let (target, heading) = split_dest(dest);
+        let Some(path) = self.resolve(target) else {
+            return format!(
+                "<span class=\"unresolved\">{}</span>",
+                escape(alias.unwrap_or(dest))
+            );
+        };
+        let extension = path
+            .extension()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_lowercase();
+        if IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+            // `|100` or `|100x200` sizes the image instead of describing it
+            let width = alias
+                .map(|alias| alias.split('x').next().unwrap_or(alias))
+                .filter(|width| width.parse::<u32>().is_ok());
+            return match width {
+                Some(width) => format!(
+                    "<img src=\"{}\" alt=\"{}\" width=\"{width}\">",
+                    escape(&self.href(path)),
+                    escape(target)
+                ),
+                None => format!(
+                    "<img src=\"{}\" alt=\"{}\">",
+                    escape(&self.href(path)),
+                    escape(alias.unwrap_or(target))
+                ),
+            };
+        }
+
+        let contents = match extension.as_str() {
+            "md" if depth < MAX_EMBED_DEPTH => std::fs::read_to_string(path).ok(),
+            _ => None,
+        };
+        let Some(contents) = contents else {
+            return format!(
+                "<a href=\"{}\">{}</a>",
+                escape(&self.href(path)),
+                escape(alias.unwrap_or(dest))
+            );
+        };
+        let (_, body) = split_frontmatter(&contents);
+        let body = strip_navigation(body);
+        let body = match heading {
+            Some(heading) => extract_section(body, heading).unwrap_or_default(),
+            None => body,
+        };
+        format!(
+            "<div class=\"embed\" data-embed=\"{}\">\n{}</div>\n",
+            escape(dest),
+            self.render_at(body, depth + 1, prefix)
+        )
+    }
+
+    /// Looks up a wikilink target by its vault relative path or file name.
+    fn resolve(&self, target: &str) -> Option<&Path> {
+        let key = target.to_lowercase();
+        let key = key.strip_suffix(".md").unwrap_or(&key);
+        self.files.get(key).map(PathBuf::as_path)
+    }
+
+    /// Formats the path relative to the vault root, percent encoding
+    /// the characters URLs reserve.
+    fn href(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+            .chars()
+            .map(|c| match c {
+                ' ' => "%20".to_string(),
+                '#' => "%23".to_string(),
+                '%' => "%25".to_string(),
+                '?' => "%3F".to_string(),
+                c => c.to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Turns a note name or heading into an HTML id, lowercase and dash separated.
+pub(crate) fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Splits a wikilink destination into its target and heading.
+fn split_dest(dest: &str) -> (&str, Option<&str>) {
+    match dest.split_once('#') {
+        Some((target, heading)) => (target.trim(), Some(heading.trim())),
+        None => (dest.trim(), None),
+    }
+}
+
+/// Whether a highlight can't continue past the event.
+fn is_block_boundary(event: &Event) -> bool {
+    match event {
+        Event::Start(tag) => !matches!(
+            tag,
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link { .. } | Tag::Image { .. }
+        ),
+        Event::End(tag) => !matches!(
+            tag,
+            TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Link
+                | TagEnd::Image
+        ),
+        _ => false,
+    }
+}
+
+/// Id of a heading, after the prefix if any.
+fn heading_id(prefix: Option<&str>, heading: &str) -> String {
+    match prefix {
+        Some(prefix) => slugify(&format!("{prefix} {heading}")),
+        None => slugify(heading),
+    }
+}
+
+/// Gives every heading an id from its text, after the prefix if any,
+/// so `[[note#Heading]]` links can point at it.
+/// Repeated headings are numbered, like `tasks-1`.
+fn add_heading_ids(events: &mut [Event], prefix: Option<&str>) {
+    let mut seen = HashMap::<String, usize>::new();
+    for i in 0..events.len() {
+        if !matches!(events[i], Event::Start(Tag::Heading { id: None, .. })) {
+            continue;
+        }
+        let text = events[i + 1..]
+            .iter()
+            .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+            .filter_map(|event| match event {
+                Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                _ => None,
+            })
+            .collect::<String>();
+        let mut slug = heading_id(prefix, &text);
+        let count = seen.entry(slug.clone()).or_default();
+        if *count > 0 {
+            slug = format!("{slug}-{count}");
+        }
+        *count += 1;
+        if let Event::Start(Tag::Heading { id, .. }) = &mut events[i] {
+            *id = Some(slug.into());
+        }
+    }
+}
+
+/// Rewrites `> [!type] Title` blockquotes into callout blocks, nested ones
+/// included, leaving their content as markdown. Code fences are skipped.
+fn expand_callouts(markdown: &str) -> String {
+    let mut expanded = String::new();
+    let mut lines = markdown.lines().peekable();
+    let mut fenced = false;
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fenced = !fenced;
+        }
+        let Some(captures) = CALLOUT.captures(line).filter(|_| !fenced) else {
+            expanded.push_str(line);
+            expanded.push('\n');
+            continue;
+        };
+        let kind = captures[1].to_lowercase();
+        let title = match captures[2].trim() {
+            "" => {
+                let mut chars = kind.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            title => title.to_string(),
+        };
+        let mut content = String::new();
+        while let Some(line) = lines.next_if(|line| line.starts_with('>')) {
+            let line = &line[1..];
+            content.push_str(line.strip_prefix(' ').unwrap_or(line));
+            content.push('\n');
+        }
+        expanded.push_str(&format!(
+            "<div class=\"callout\" data-callout=\"{kind}\">\n<div class=\"callout-title\">{}</div>\n<div class=\"callout-content\">\n\n{}\n</div>\n</div>\n\n",
+            escape(&title),
+            expand_callouts(&content)
+        ));
+    }
+    expanded
+}
+
+/// Finds the section under the heading, up to the next heading of the same
+/// or a higher level. The heading itself is included.
+fn extract_section<'a>(markdown: &'a str, heading: &str) -> Option<&'a str> {
+    let mut section: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    let mut fenced = false;
+    for line in markdown.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fenced = !fenced;
+        } else if let Some((level, text)) = parse_heading(line).filter(|_| !fenced) {
+            match section {
+                None if text.eq_ignore_ascii_case(heading) => section = Some((offset, level)),
+                Some((start, section_level)) if level <= section_level => {
+                    return Some(&markdown[start..offset]);
+                }
+                _ => (),
+            }
+        }
+        offset += line.len();
+    }
+    section.map(|(start, _)| &markdown[start..])
+}
+
+/// Parses an ATX heading into its level and text.
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let text = &line[level..];
+    match (1..=6).contains(&level) && (text.is_empty() || text.starts_with(char::is_whitespace)) {
+        true => Some((level, text.trim().trim_end_matches('#').trim_end())),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::write_test_vault;
+
+    fn renderer(files: &[(&str, &str)]) -> anyhow::Result<(tempfile::TempDir, Renderer)> {
+        let vault = write_test_vault(files)?;
+        let config = AppConfig::builder(vault.path()).try_build()?;
+        let renderer = Renderer::try_new(&config)?;
+        Ok((vault, renderer))
+    }
+
+    #[test]
+    fn test_render_links() -> anyhow::Result<()> {
+        let desc = "Test rendering wikilinks";
+        let (_vault, renderer) = renderer(&[
+            ("projects/second brain.md", "# Plan"),
+            ("2025-12-30.md", ""),
+            ("assets/diagram.png", ""),
+            ("assets/q&a \"draft\".pdf", ""),
+        ])?;
+        let test_cases = [
+            (
+                "[[second brain]]",
+                "<p><a href=\"projects/second%20brain.md\">second brain</a></p>\n",
+            ),
+            (
+                "[[projects/second brain#Plan|the plan]]",
+                "<p><a href=\"projects/second%20brain.md#plan\">the plan</a></p>\n",
+            ),
+            (
+                "[[#Local Heading]]",
+                "<p><a href=\"#local-heading\">#Local Heading</a></p>\n",
+            ),
+            (
+                "[[missing]]",
+                "<p><a class=\"unresolved\">missing</a></p>\n",
+            ),
+            ("`[[code]]`", "<p><code>[[code]]</code></p>\n"),
+            (
+                "![[diagram.png|200]]",
+                "<p><img src=\"assets/diagram.png\" alt=\"diagram.png\" width=\"200\"></p>\n",
+            ),
+            (
+                "![[q&a \"draft\".pdf]]",
+                "<p><a href=\"assets/q&amp;a%20&quot;draft&quot;.pdf\">q&amp;a &quot;draft&quot;.pdf</a></p>\n",
+            ),
+        ];
+        test_cases.into_iter().for_each(|(markdown, want)| {
+            assert_eq!(want, renderer.render(markdown), "{desc}: {markdown}");
+        });
+
+        let renderer = renderer.with_anchors([("2025-12-30".to_string(), "note-1".to_string())]);
+        assert_eq!(
+            "<p><a href=\"#note-1\">yesterday</a></p>\n",
+            renderer.render("[[2025-12-30|yesterday]]"),
+            "{desc}: anchors"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_embeds() -> anyhow::Result<()> {
+        let desc = "Test transcluding embedded notes and their sections";
+        let (_vault, renderer) = renderer(&[
+            (
+                "project.md",
+                "---\ntags: [a]\n---\nintro\n\n## Tasks\n\n- [x] done\n\n### Later\n\nsoon\n\n## Notes\n\nskipped",
+            ),
+            ("loop.md", "again ![[loop]]"),
+        ])?;
+        let got = renderer.render("![[project#Tasks]]");
+        let want = [
+            "<div class=\"embed\" data-embed=\"project#Tasks\">",
+            "<h2 id=\"tasks\">Tasks</h2>",
+            "<ul>",
+            "<li><input disabled=\"\" type=\"checkbox\" checked=\"\"/>",
+            "done</li>",
+            "</ul>",
+            "<h3 id=\"later\">Later</h3>",
+            "<p>soon</p>",
+            "</div>",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(want, got, "{desc}");
+
+        let got = renderer.render("![[loop]]");
+        assert_eq!(
+            MAX_EMBED_DEPTH,
+            got.matches("<div class=\"embed\"").count(),
+            "{desc}: recursion"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_obsidian_syntax() -> anyhow::Result<()> {
+        let desc = "Test rendering callouts and highlights";
+        let (_vault, renderer) = renderer(&[])?;
+        let test_cases = [
+            (
+                "> [!warning] Mind the <gap>\n> **careful**\n\nafter",
+                "<div class=\"callout\" data-callout=\"warning\">\n<div class=\"callout-title\">Mind the &lt;gap&gt;</div>\n<div class=\"callout-content\">\n<p><strong>careful</strong></p>\n</div>\n</div>\n<p>after</p>\n",
+            ),
+            (
+                "> [!tip]\n> > [!note]\n> > inner",
+                "<div class=\"callout\" data-callout=\"tip\">\n<div class=\"callout-title\">Tip</div>\n<div class=\"callout-content\">\n<div class=\"callout\" data-callout=\"note\">\n<div class=\"callout-title\">Note</div>\n<div class=\"callout-content\">\n<p>inner</p>\n</div>\n</div>\n</div>\n</div>\n",
+            ),
+            (
+                "> plain quote",
+                "<blockquote>\n<p>plain quote</p>\n</blockquote>\n",
+            ),
+            (
+                "a ==marked **bold**== text",
+                "<p>a <mark>marked <strong>bold</strong></mark> text</p>\n",
+            ),
+            ("a == b", "<p>a == b</p>\n"),
+            (
+                "```\n> [!note]\na == b\n```",
+                "<pre><code>&gt; [!note]\na == b\n</code></pre>\n",
+            ),
+        ];
+        test_cases.into_iter().for_each(|(markdown, want)| {
+            assert_eq!(want, renderer.render(markdown), "{desc}: {markdown}");
+        });
+        Ok(())
+    }
+}
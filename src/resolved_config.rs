@@ -0,0 +1,205 @@
+use std::{collections::BTreeMap, fmt::Display, path::PathBuf};
+
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::ResolvedConfig;
+}
+
+/// Snapshot of the effective app configuration, with every default
+/// filled in and every path made absolute.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ResolvedConfig {
+    config_file: Option<PathBuf>,
+    vault: PathBuf,
+    day_starts_at: String,
+    /// Configured timezone, `local` if the system's timezone is used.
+    timezone: String,
+    git: ResolvedGit,
+    rollup: ResolvedRollup,
+    periodical: BTreeMap<Periodical, ResolvedPeriod>,
+}
+
+/// Effective configuration of git auto commits.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ResolvedGit {
+    auto_commit: bool,
+    message: String,
+}
+
+/// Effective configuration of roll-up links.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ResolvedRollup {
+    enabled: bool,
+    heading: String,
+    mark_existing: bool,
+}
+
+/// Effective configuration of a single Periodical.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ResolvedPeriod {
+    dir: PathBuf,
+    fmt: String,
+    template: Option<PathBuf>,
+    /// Only resolved for `Periodical::Week`.
+    week_start: Option<String>,
+    /// Only resolved for `Periodical::Week`.
+    week_numbering: Option<String>,
+    /// Note path that would be opened for the resolved date.
+    example: PathBuf,
+}
+
+impl ResolvedConfig {
+    /// Resolves every Periodical of the AppConfig against the passed in date.
+    pub fn try_new(config: &AppConfig, date: DateTime<FixedOffset>) -> Result<Self, RuntimeError> {
+        let periodical = Periodical::iter()
+            .map(|period| {
+                let is_week = period == Periodical::Week;
+                let resolved = ResolvedPeriod {
+                    dir: std::path::absolute(config.get_parent_dir(period))?,
+                    fmt: config.get_fmt(period).to_string(),
+                    template: config.try_format_absolute_template_path(period)?,
+                    week_start: is_week.then(|| config.get_week_start().to_string()),
+                    week_numbering: is_week.then(|| config.get_week_numbering().to_string()),
+                    example: config.try_format_absolute_note_path(period, date)?,
+                };
+                Ok((period, resolved))
+            })
+            .collect::<Result<_, RuntimeError>>()?;
+        let time = config.get_time_config();
+        let git = config.get_git_config();
+        let rollup = config.get_rollup_config();
+
+        Ok(Self {
+            config_file: config.get_config_file().map(|f| f.to_owned()),
+            vault: std::path::absolute(config.get_vault_root())?,
+            day_starts_at: time.get_day_start().format("%H:%M").to_string(),
+            timezone: time
+                .get_timezone()
+                .map(|tz| tz.to_string())
+                .unwrap_or("local".into()),
+            git: ResolvedGit {
+                auto_commit: git.get_auto_commit(),
+                message: git.get_message().to_string(),
+            },
+            rollup: ResolvedRollup {
+                enabled: rollup.get_enabled(),
+                heading: rollup.get_heading().to_string(),
+                mark_existing: rollup.get_mark_existing(),
+            },
+            periodical,
+        })
+    }
+    pub fn to_json(&self) -> Result<String, RuntimeError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+    pub fn to_toml(&self) -> Result<String, RuntimeError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+}
+
+impl Display for ResolvedConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let config_file = self
+            .config_file
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or("none".into());
+        writeln!(f, "config file: {config_file}")?;
+        writeln!(f, "vault:       {}", self.vault.display())?;
+        writeln!(f, "day starts:  {}", self.day_starts_at)?;
+        writeln!(f, "timezone:    {}", self.timezone)?;
+        writeln!(f, "git commits: {}", self.git.auto_commit)?;
+        writeln!(f, "git message: {}", self.git.message)?;
+        writeln!(f, "roll-ups:    {}", self.rollup.enabled)?;
+        writeln!(f, "heading:     {}", self.rollup.heading)?;
+        write!(f, "mark exists: {}", self.rollup.mark_existing)?;
+
+        for (period, resolved) in &self.periodical {
+            let template = resolved
+                .template
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or("none".into());
+            write!(f, "\n\n[{period}]")?;
+            write!(f, "\ndir:         {}", resolved.dir.display())?;
+            write!(f, "\nfmt:         {}", resolved.fmt)?;
+            write!(f, "\ntemplate:    {template}")?;
+            if let Some(week_start) = &resolved.week_start {
+                write!(f, "\nweek start:  {week_start}")?;
+            }
+            if let Some(week_numbering) = &resolved.week_numbering {
+                write!(f, "\nnumbering:   {week_numbering}")?;
+            }
+            write!(f, "\nexample:     {}", resolved.example.display())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone};
+
+    use super::*;
+    use crate::app_config::TomlConfig;
+
+    /// Config with the crate root as the vault, since AppConfig
+    /// validates its vault directory.
+    fn config() -> anyhow::Result<AppConfig> {
+        let toml = toml::from_str::<TomlConfig>("[vault]\ndir = \".\"")?;
+        Ok(AppConfig::try_from(toml)?)
+    }
+
+    #[test]
+    fn test_resolved_defaults() -> anyhow::Result<()> {
+        let desc = "Test unconfigured periodicals resolve to defaults";
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
+        let got = ResolvedConfig::try_new(&config()?, date)?;
+
+        let test_cases = [
+            (Periodical::Day, DEFAULT_DAY, "2025-12-30.md"),
+            (Periodical::Week, DEFAULT_WEEK, "2026-W01.md"),
+            (Periodical::Month, DEFAULT_MONTH, "2025-12.md"),
+            (Periodical::Year, DEFAULT_YEAR, "2025.md"),
+        ];
+        test_cases.into_iter().for_each(|(period, fmt, file_name)| {
+            let resolved = &got.periodical[&period];
+            assert_eq!(fmt, resolved.fmt, "{desc}: {period}");
+            assert_eq!(None, resolved.template, "{desc}: {period}");
+            assert!(resolved.example.ends_with(file_name), "{desc}: {period}");
+            assert!(resolved.example.is_absolute(), "{desc}: {period}");
+        });
+        assert!(got.config_file.is_none(), "{desc}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolved_serialization() -> anyhow::Result<()> {
+        let desc = "Test resolved config serializes to every output format";
+        let date = Local
+            .with_ymd_and_hms(2025, 12, 30, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
+        let got = ResolvedConfig::try_new(&config()?, date)?;
+
+        let json = serde_json::from_str::<serde_json::Value>(&got.to_json()?)?;
+        assert_eq!(json["periodical"]["week"]["fmt"], DEFAULT_WEEK, "{desc}");
+
+        let toml = toml::from_str::<toml::Table>(&got.to_toml()?)?;
+        assert_eq!(
+            toml["periodical"]["month"]["fmt"].as_str(),
+            Some(DEFAULT_MONTH)
+        );
+
+        assert!(got.to_string().contains("[year]"), "{desc}");
+        Ok(())
+    }
+}
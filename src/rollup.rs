@@ -0,0 +1,177 @@
+use chrono::{DateTime, Days, FixedOffset, NaiveDate};
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::RollupConfig;
+}
+
+const DEFAULT_HEADING: &str = "## Overview";
+
+/// Configuration of the links to smaller periodicals written into new notes.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct RollupConfig {
+    pub(crate) enabled: Option<bool>,
+    pub(crate) heading: Option<String>,
+    pub(crate) mark_existing: Option<bool>,
+}
+
+impl RollupConfig {
+    /// Getter for whether new week, month and year notes list their children.
+    /// Defaults to false.
+    pub fn get_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default()
+    }
+    /// Getter for the heading the list of links goes under.
+    pub fn get_heading(&self) -> &str {
+        self.heading.as_deref().unwrap_or(DEFAULT_HEADING)
+    }
+    /// Getter for whether links are rendered as checked tasks
+    /// if their note exists, and unchecked ones otherwise.
+    /// Defaults to false.
+    pub fn get_mark_existing(&self) -> bool {
+        self.mark_existing.unwrap_or_default()
+    }
+}
+
+/// Attempts to render the roll-up section of a new note:
+/// weeks list their days, months their weeks and each week's days,
+/// and years their months.
+/// Returns None if roll-ups are disabled or the periodical has no children.
+pub(crate) fn try_render_rollup(
+    config: &AppConfig,
+    period: Periodical,
+    date: DateTime<FixedOffset>,
+) -> Result<Option<String>, RuntimeError> {
+    let rollup = config.get_rollup_config();
+    if !rollup.get_enabled() || period.child().is_none() {
+        return Ok(None);
+    }
+    let week_start = config.get_week_start();
+    let start = period.start_of(date.date_naive(), week_start);
+    let end = period.end_of(date.date_naive(), week_start);
+    let depth = match period {
+        Periodical::Month => 2,
+        _ => 1,
+    };
+
+    let mut lines = vec![rollup.get_heading().to_string(), String::new()];
+    push_links(config, period, start, end, depth, 0, &mut lines)?;
+    Ok(Some(lines.join("\n")))
+}
+
+/// Appends an indented link for every child of the period overlapping
+/// `start..=end`, followed by the links of its own children, down to `depth`.
+fn push_links(
+    config: &AppConfig,
+    period: Periodical,
+    start: NaiveDate,
+    end: NaiveDate,
+    depth: usize,
+    indent: usize,
+    lines: &mut Vec<String>,
+) -> Result<(), RuntimeError> {
+    let Some(child) = period.child().filter(|_| depth > 0) else {
+        return Ok(());
+    };
+    let week_start = config.get_week_start();
+    let mut day = start;
+    while day <= end {
+        let child_date = config.at_midnight(day);
+        let name = config.format_date(child, child_date);
+        let marker = match config.get_rollup_config().get_mark_existing() {
+            true => match config
+                .try_format_absolute_note_path(child, child_date)?
+                .exists()
+            {
+                true => "[x] ",
+                false => "[ ] ",
+            },
+            false => "",
+        };
+        lines.push(format!("{}- {marker}[[{name}]]", "    ".repeat(indent)));
+
+        // children are cut off at the bounds of the period being rolled up
+        let child_end = child.end_of(day, week_start).min(end);
+        push_links(config, child, day, child_end, depth - 1, indent + 1, lines)?;
+        day = child_end
+            .checked_add_days(Days::new(1))
+            .ok_or(RuntimeError::DateOutOfRange)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollup() -> anyhow::Result<()> {
+        let desc = "Test roll-ups list the children of a period";
+        let vault = tempfile::tempdir()?;
+        let config = AppConfig::builder(vault.path())
+            .rollup(true)
+            .rollup_heading("## Days")
+            .try_build()?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap());
+
+        let got = try_render_rollup(&config, Periodical::Week, date)?;
+        let want = [
+            "## Days",
+            "",
+            "- [[2025-12-29]]",
+            "- [[2025-12-30]]",
+            "- [[2025-12-31]]",
+            "- [[2026-01-01]]",
+            "- [[2026-01-02]]",
+            "- [[2026-01-03]]",
+            "- [[2026-01-04]]",
+        ]
+        .join("\n");
+        assert_eq!(Some(want), got, "{desc}: week");
+
+        let got = try_render_rollup(&config, Periodical::Year, date)?.unwrap();
+        assert_eq!(14, got.lines().count(), "{desc}: year");
+        assert!(got.ends_with("- [[2026-12]]"), "{desc}: year");
+
+        let got = try_render_rollup(&config, Periodical::Day, date)?;
+        assert_eq!(None, got, "{desc}: day");
+        Ok(())
+    }
+
+    #[test]
+    fn test_month_rollup() -> anyhow::Result<()> {
+        let desc = "Test month roll-ups list weeks and their days within the month";
+        let vault = tempfile::tempdir()?;
+        std::fs::write(vault.path().join("2026-W01.md"), "")?;
+        std::fs::write(vault.path().join("2026-02-01.md"), "")?;
+        let config = AppConfig::builder(vault.path())
+            .rollup(true)
+            .rollup_mark_existing(true)
+            .try_build()?;
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2026, 2, 14).unwrap());
+
+        let got = try_render_rollup(&config, Periodical::Month, date)?.unwrap();
+        let lines = got.lines().collect::<Vec<_>>();
+        // 5 weeks and 28 days of February 2026, after the heading and blank line
+        assert_eq!(2 + 5 + 28, lines.len(), "{desc}: {got}");
+        assert_eq!("## Overview", lines[0], "{desc}");
+        assert_eq!("- [ ] [[2026-W05]]", lines[2], "{desc}: first week");
+        assert_eq!("    - [x] [[2026-02-01]]", lines[3], "{desc}: cut off day");
+        assert_eq!("- [ ] [[2026-W06]]", lines[4], "{desc}: second week");
+        assert_eq!("    - [ ] [[2026-02-02]]", lines[5], "{desc}");
+
+        let date = config.at_midnight(NaiveDate::from_ymd_opt(2025, 12, 30).unwrap());
+        let got = try_render_rollup(&config, Periodical::Month, date)?.unwrap();
+        assert!(
+            got.contains("\n- [x] [[2026-W01]]\n"),
+            "{desc}: existing week"
+        );
+        assert!(
+            got.ends_with("    - [ ] [[2025-12-31]]"),
+            "{desc}: last day"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::RunOptions;
+}
+
+/// Options of a single run, set on the command line rather than in the config file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RunOptions {
+    dry_run: bool,
+    json: bool,
+    history: Option<History>,
+}
+
+impl RunOptions {
+    /// Sets whether writes to the vault are only reported instead of made.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+    /// Getter for whether writes to the vault are only reported.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+    /// Sets whether output, like dry run reports, is printed as JSON.
+    pub fn with_json(mut self, json: bool) -> Self {
+        self.json = json;
+        self
+    }
+    /// Getter for whether output is printed as JSON.
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
+    /// Sets the history opened notes are recorded in.
+    pub fn with_history(mut self, history: Option<History>) -> Self {
+        self.history = history;
+        self
+    }
+    /// Getter for the history opened notes are recorded in.
+    /// Returns None if opened notes aren't recorded.
+    pub fn get_history(&self) -> Option<&History> {
+        self.history.as_ref()
+    }
+}
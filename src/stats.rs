@@ -0,0 +1,282 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::Stats;
+}
+
+/// Most linked notes listed.
+const MOST_LINKED: usize = 5;
+
+/// Journaling statistics of a periodical and the vault as a whole.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Stats {
+    period: Periodical,
+    notes: usize,
+    /// Consecutive notes up to the current period.
+    /// The current period's note missing doesn't break the streak yet.
+    current_streak: u32,
+    longest_streak: u32,
+    /// Notes of the periodical per `YYYY-MM` month of their start date.
+    per_month: BTreeMap<String, usize>,
+    /// Average words of the periodical's notes, without generated headings.
+    average_words: usize,
+    /// Words of every note in the vault, without generated headings.
+    vault_words: usize,
+    most_linked: Vec<LinkCount>,
+}
+
+/// Number of wikilinks pointing at a note.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct LinkCount {
+    note: String,
+    links: usize,
+}
+
+impl Stats {
+    /// Attempts to collect the statistics of the periodical's notes,
+    /// with streaks counted back from the period the date falls in.
+    pub fn try_new(
+        config: &AppConfig,
+        period: Periodical,
+        today: NaiveDate,
+    ) -> Result<Self, RuntimeError> {
+        let mut dates = vec![];
+        let mut period_words = 0;
+        let mut vault_words = 0;
+        let mut links = HashMap::<String, usize>::new();
+        for path in list_notes(config.get_vault_root())? {
+            // unreadable notes still count, just without words or links
+            let contents = std::fs::read_to_string(&path).unwrap_or_default();
+            let (_, body) = split_frontmatter(&contents);
+            let body = strip_navigation(body);
+            let words = body.split_whitespace().count();
+            vault_words += words;
+            wikilinks(body).filter(WikiLink::is_note).for_each(|link| {
+                *links
+                    .entry(link.get_note_name().to_lowercase())
+                    .or_default() += 1
+            });
+
+            if let Some((note_period, date)) = config.try_parse_note_path(&path)?
+                && note_period == period
+            {
+                dates.push(date);
+                period_words += words;
+            }
+        }
+
+        let mut per_month = BTreeMap::new();
+        dates.iter().for_each(|date| {
+            *per_month
+                .entry(date.format("%Y-%m").to_string())
+                .or_default() += 1;
+        });
+        let mut most_linked = links
+            .into_iter()
+            .map(|(note, links)| LinkCount { note, links })
+            .collect::<Vec<_>>();
+        most_linked.sort_by(|a, b| b.links.cmp(&a.links).then(a.note.cmp(&b.note)));
+        most_linked.truncate(MOST_LINKED);
+        let (current_streak, longest_streak) = match dates.iter().min() {
+            Some(&earliest) => try_count_streaks(config, period, today, earliest)?,
+            None => (0, 0),
+        };
+
+        Ok(Self {
+            period,
+            notes: dates.len(),
+            current_streak,
+            longest_streak,
+            per_month,
+            average_words: period_words.checked_div(dates.len()).unwrap_or_default(),
+            vault_words,
+            most_linked,
+        })
+    }
+    pub fn to_json(&self) -> Result<String, RuntimeError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Attempts to count the current and longest streak of existing notes,
+/// stepping back one period at a time from today's until the earliest note.
+/// Note paths are formatted from the config, so custom dirs and formats count.
+fn try_count_streaks(
+    config: &AppConfig,
+    period: Periodical,
+    today: NaiveDate,
+    earliest: NaiveDate,
+) -> Result<(u32, u32), RuntimeError> {
+    let exists = |date| -> Result<bool, RuntimeError> {
+        Ok(config.try_format_absolute_note_path(period, date)?.exists())
+    };
+    let mut date = Some(config.at_midnight(period.start_of(today, config.get_week_start())));
+    // the current period isn't over, so its note can still be written
+    if let Some(today) = date
+        && !exists(today)?
+    {
+        date = period.get_prev(today, 1);
+    }
+
+    let mut current = None;
+    let (mut run, mut longest) = (0, 0);
+    while let Some(day) = date.filter(|date| date.date_naive() >= earliest) {
+        match exists(day)? {
+            true => run += 1,
+            false => {
+                current.get_or_insert(run);
+                longest = longest.max(run);
+                run = 0;
+            }
+        }
+        date = period.get_prev(day, 1);
+    }
+    Ok((current.unwrap_or(run), longest.max(run)))
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let periods = |n: u32| match n {
+            1 => format!("1 {}", self.period),
+            n => format!("{n} {}s", self.period),
+        };
+        writeln!(f, "{:<16}{}", format!("{} notes:", self.period), self.notes)?;
+        writeln!(f, "current streak: {}", periods(self.current_streak))?;
+        writeln!(f, "longest streak: {}", periods(self.longest_streak))?;
+        writeln!(f, "average words:  {}", self.average_words)?;
+        write!(f, "vault words:    {}", self.vault_words)?;
+
+        if !self.per_month.is_empty() {
+            write!(f, "\n\nnotes per month:")?;
+            for (month, notes) in &self.per_month {
+                write!(f, "\n{month}  {notes:>3}")?;
+            }
+        }
+        if !self.most_linked.is_empty() {
+            write!(f, "\n\nmost linked:")?;
+            for link in &self.most_linked {
+                write!(f, "\n{:>4}  {}", link.links, link.note)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::write_test_vault;
+
+    #[test]
+    fn test_streaks() -> anyhow::Result<()> {
+        let desc = "Test counting streaks with custom dirs and formats";
+        let dir = write_test_vault(&[
+            ("journal/20.12.2025.md", ""),
+            ("journal/21.12.2025.md", ""),
+            ("journal/22.12.2025.md", ""),
+            ("journal/23.12.2025.md", ""),
+            ("journal/26.12.2025.md", ""),
+            ("journal/27.12.2025.md", ""),
+            // written with the default format, so it doesn't count
+            ("journal/2025-12-28.md", ""),
+        ])?;
+        let config = AppConfig::builder(dir.path())
+            .dir(Periodical::Day, "journal")
+            .fmt(Periodical::Day, "%d.%m.%Y")
+            .try_build()?;
+        let test_cases = [
+            ((2025, 12, 27), (2, 4)),
+            // today's note can still be written
+            ((2025, 12, 28), (2, 4)),
+            ((2025, 12, 29), (0, 4)),
+            ((2025, 12, 23), (4, 4)),
+        ];
+        test_cases.into_iter().try_for_each(|((y, m, d), want)| {
+            let today = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let stats = Stats::try_new(&config, Periodical::Day, today)?;
+            assert_eq!(
+                want,
+                (stats.current_streak, stats.longest_streak),
+                "{desc}: {today}"
+            );
+            anyhow::Ok(())
+        })?;
+
+        let stats = Stats::try_new(
+            &config,
+            Periodical::Week,
+            NaiveDate::from_ymd_opt(2025, 12, 27).unwrap(),
+        )?;
+        assert_eq!(
+            (0, 0, 0),
+            (stats.notes, stats.current_streak, stats.longest_streak),
+            "{desc}: weeks"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats() -> anyhow::Result<()> {
+        let desc = "Test counting notes, words and links to notes";
+        let dir = write_test_vault(&[
+            (
+                "2025-11-30.md",
+                "[[2025-11-29]] - [[2025-12-01]]\n\nworked on [[Project]]",
+            ),
+            (
+                "2025-12-01.md",
+                "---\ntags: [a]\n---\nmore [[project]] and [[people/ada|Ada]]",
+            ),
+            (
+                "2025-W49.md",
+                "[[2025-W48]] - [[2025-W50]]\n\n[[dir/project.md]] review",
+            ),
+            ("project.md", "one two three ![[chart.png]]"),
+        ])?;
+        // not valid UTF-8, which mustn't fail the stats
+        std::fs::write(dir.path().join("binary.md"), [0xff, 0xfe])?;
+        let config = AppConfig::builder(dir.path()).try_build()?;
+        let stats = Stats::try_new(
+            &config,
+            Periodical::Day,
+            NaiveDate::from_ymd_opt(2025, 12, 1).unwrap(),
+        )?;
+
+        assert_eq!(2, stats.notes, "{desc}: notes");
+        assert_eq!(
+            (2, 2),
+            (stats.current_streak, stats.longest_streak),
+            "{desc}: streaks"
+        );
+        assert_eq!(
+            BTreeMap::from([("2025-11".to_string(), 1), ("2025-12".to_string(), 1)]),
+            stats.per_month,
+            "{desc}: per month"
+        );
+        assert_eq!(3, stats.average_words, "{desc}: average words");
+        assert_eq!(3 + 4 + 2 + 4, stats.vault_words, "{desc}: vault words");
+        assert_eq!(
+            vec![
+                LinkCount {
+                    note: "project".into(),
+                    links: 3
+                },
+                LinkCount {
+                    note: "ada".into(),
+                    links: 1
+                },
+            ],
+            stats.most_linked,
+            "{desc}: most linked"
+        );
+        Ok(())
+    }
+}
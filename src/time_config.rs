@@ -0,0 +1,225 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{
+    DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta, TimeZone,
+    Utc,
+};
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{TimeConfig, Timezone};
+}
+
+/// Longest stretch of wall clock time a timezone change skips,
+/// a whole day when Samoa crossed the date line.
+const MAX_GAP_HOURS: i64 = 48;
+
+/// Configuration of when a day starts and which timezone it starts in.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct TimeConfig {
+    pub(crate) day_starts_at: Option<DayStart>,
+    pub(crate) timezone: Option<Timezone>,
+}
+
+/// Time of day a new day starts at, expressed as `HH:MM`.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(try_from = "String")]
+pub(crate) struct DayStart(pub(crate) NaiveTime);
+
+/// Timezone the current date is taken in.
+/// Either an IANA name, like `Europe/Rome`, or a fixed offset, like `+02:00`.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(try_from = "String")]
+pub enum Timezone {
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+impl TimeConfig {
+    /// Getter for the time of day a new day starts at.
+    /// Returns midnight if none is configured.
+    pub fn get_day_start(&self) -> NaiveTime {
+        self.day_starts_at.map(|d| d.0).unwrap_or(NaiveTime::MIN)
+    }
+    /// Getter for the configured timezone.
+    /// Returns None if the system's local timezone is used.
+    pub fn get_timezone(&self) -> Option<Timezone> {
+        self.timezone
+    }
+    /// Converts an instant into the configured timezone and shifts it back
+    /// by the configured start of day.
+    /// An instant before the day starts is counted as part of the previous day.
+    pub fn localize(&self, instant: DateTime<Utc>) -> DateTime<FixedOffset> {
+        let date = match self.timezone {
+            Some(Timezone::Named(tz)) => instant.with_timezone(&tz).fixed_offset(),
+            Some(Timezone::Fixed(offset)) => instant.with_timezone(&offset),
+            None => instant.with_timezone(&Local).fixed_offset(),
+        };
+        date - (self.get_day_start() - NaiveTime::MIN)
+    }
+    /// Anchors a date at midnight in the configured timezone.
+    pub fn at_midnight(&self, date: NaiveDate) -> DateTime<FixedOffset> {
+        self.at_local(date.and_time(NaiveTime::MIN))
+    }
+    /// Anchors a wall clock date time in the configured timezone.
+    /// Times skipped by daylight saving take the offset before the change,
+    /// and repeated times the earlier of their offsets.
+    pub fn at_local(&self, local: NaiveDateTime) -> DateTime<FixedOffset> {
+        let offset = match self.timezone {
+            Some(Timezone::Named(tz)) => offset_at(&tz, local),
+            Some(Timezone::Fixed(offset)) => Some(offset),
+            None => offset_at(&Local, local),
+        };
+        let offset = offset.unwrap_or(Utc.fix());
+        DateTime::from_naive_utc_and_offset(local - offset, offset)
+    }
+    /// Returns the clock's current date time, localized with the configuration.
+    pub fn now(&self, clock: &dyn Clock) -> DateTime<FixedOffset> {
+        self.localize(clock.now())
+    }
+}
+
+/// Finds the zone's offset at the wall clock time.
+/// Times in a daylight saving gap step back an hour at a time
+/// to the last one before the gap.
+fn offset_at<T: TimeZone>(tz: &T, local: NaiveDateTime) -> Option<FixedOffset> {
+    (0..=MAX_GAP_HOURS).find_map(|hours| {
+        let local = local.checked_sub_signed(TimeDelta::hours(hours))?;
+        tz.offset_from_local_datetime(&local)
+            .earliest()
+            .map(|o| o.fix())
+    })
+}
+
+impl TryFrom<String> for DayStart {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        NaiveTime::parse_from_str(&value, "%H:%M")
+            .map(Self)
+            .map_err(|e| format!("day_starts_at \"{value}\" isn't formatted as HH:MM: {e}"))
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(tz) = Tz::from_str(s) {
+            return Ok(Self::Named(tz));
+        }
+        FixedOffset::from_str(s)
+            .map(Self::Fixed)
+            .map_err(|_| format!("timezone \"{s}\" isn't an IANA name or a fixed offset"))
+    }
+}
+
+impl Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Named(tz) => write!(f, "{tz}"),
+            Self::Fixed(offset) => write!(f, "{offset}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_localize() -> anyhow::Result<()> {
+        let desc = "Test shifting instants by timezone and start of day";
+        // 2026-01-01T01:30 in Rome, 2025-12-31T19:30 in New York
+        let instant = Utc.with_ymd_and_hms(2026, 1, 1, 0, 30, 0).unwrap();
+
+        let test_cases = [
+            (
+                "timezone = \"Europe/Rome\"",
+                "2026-01-01",
+                "Case named timezone",
+            ),
+            ("timezone = \"-05:00\"", "2025-12-31", "Case fixed offset"),
+            (
+                "timezone = \"Europe/Rome\"\nday_starts_at = \"04:00\"",
+                "2025-12-31",
+                "Case shifted into the previous day",
+            ),
+            (
+                "timezone = \"Europe/Rome\"\nday_starts_at = \"01:00\"",
+                "2026-01-01",
+                "Case shifted within the same day",
+            ),
+        ];
+        test_cases.iter().try_for_each(|(s, want, desc_case)| {
+            let config = toml::from_str::<TimeConfig>(s)?;
+            let got = config.localize(instant).format("%Y-%m-%d").to_string();
+
+            assert_eq!(*want, got, "{desc}: {desc_case}");
+            anyhow::Ok(())
+        })
+    }
+
+    #[test]
+    fn test_at_midnight() -> anyhow::Result<()> {
+        let desc = "Test anchoring dates in the configured timezone";
+        let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        let test_cases = [
+            ("timezone = \"Europe/Rome\"", "2025-07-01T00:00:00+02:00"),
+            ("timezone = \"-05:00\"", "2025-07-01T00:00:00-05:00"),
+        ];
+        test_cases.iter().try_for_each(|(s, want)| {
+            let config = toml::from_str::<TimeConfig>(s)?;
+            let got = config.at_midnight(date).to_rfc3339();
+
+            assert_eq!(*want, got, "{desc}: {s}");
+            anyhow::Ok(())
+        })?;
+
+        let desc = "Test midnights skipped by daylight saving take the offset before";
+        let test_cases = [
+            // clocks jumped from midnight to 01:00 when DST started
+            (
+                "America/Sao_Paulo",
+                (2018, 11, 4),
+                "2018-11-04T00:00:00-03:00",
+            ),
+            // Samoa skipped December 30 entirely, crossing the date line
+            ("Pacific/Apia", (2011, 12, 30), "2011-12-30T00:00:00-10:00"),
+        ];
+        test_cases.iter().try_for_each(|(tz, (y, m, d), want)| {
+            let config = toml::from_str::<TimeConfig>(&format!("timezone = \"{tz}\""))?;
+            let date = NaiveDate::from_ymd_opt(*y, *m, *d).unwrap();
+            let got = config.at_midnight(date).to_rfc3339();
+
+            assert_eq!(*want, got, "{desc}: {tz}");
+            anyhow::Ok(())
+        })
+    }
+
+    #[test]
+    fn test_de_invalid() {
+        let desc = "Test invalid time configurations fail to deserialize";
+        let test_cases = [
+            "day_starts_at = \"4am\"",
+            "day_starts_at = \"25:00\"",
+            "timezone = \"Mars/Olympus_Mons\"",
+        ];
+        test_cases.iter().for_each(|s| {
+            assert!(toml::from_str::<TimeConfig>(s).is_err(), "{desc}: {s}");
+        });
+    }
+}
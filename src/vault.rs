@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use crate::prelude::*;
+
+pub mod prelude {
+    pub use super::{list_files, list_notes};
+}
+
+/// Recursively collects every markdown note under the passed in directory.
+/// Hidden files and directories, like `.obsidian` or `.git`, are skipped.
+/// Returned paths are sorted.
+pub fn list_notes(root: &Path) -> Result<Vec<PathBuf>, RuntimeError> {
+    let mut notes = list_files(root)?;
+    notes.retain(|path| path.extension().is_some_and(|ext| ext == "md"));
+    Ok(notes)
+}
+
+/// Recursively collects every file under the passed in directory,
+/// attachments included.
+/// Hidden files and directories are skipped, and returned paths are sorted.
+pub fn list_files(root: &Path) -> Result<Vec<PathBuf>, RuntimeError> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let path = entry.path();
+            match entry.file_type()?.is_dir() {
+                true => dirs.push(path),
+                false => files.push(path),
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Writes the files, by vault relative path and contents,
+/// into a new temporary vault for tests.
+#[cfg(test)]
+pub(crate) fn write_test_vault(files: &[(&str, &str)]) -> anyhow::Result<tempfile::TempDir> {
+    let vault = tempfile::tempdir()?;
+    files.iter().try_for_each(|(file, contents)| {
+        let path = vault.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(path, contents)
+    })?;
+    Ok(vault)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_notes() -> anyhow::Result<()> {
+        let desc = "Test listing only visible markdown notes";
+        let vault = tempfile::tempdir()?;
+        let files = [
+            "2025-12-30.md",
+            "periodic/day/2025-12-31.md",
+            "periodic/week/2026-W01.md",
+            "attachments/image.png",
+            ".obsidian/workspace.md",
+            ".hidden.md",
+        ];
+        files.iter().try_for_each(|f| {
+            let path = vault.path().join(f);
+            std::fs::create_dir_all(path.parent().unwrap())?;
+            std::fs::write(path, "")
+        })?;
+
+        let got = list_notes(vault.path())?;
+        let want = files[..3]
+            .iter()
+            .map(|f| vault.path().join(f))
+            .collect::<Vec<_>>();
+
+        assert_eq!(want, got, "{desc}");
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::Deserialize;
+use strum_macros::Display;
+
+pub mod prelude {
+    pub use super::{WeekNumbering, WeekStart};
+}
+
+/// Day of the week a week starts on.
+#[derive(Debug, Default, Display, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+/// Scheme used to count weeks within a year.
+#[derive(Debug, Default, Display, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum WeekNumbering {
+    /// ISO 8601 weeks, counted in the ISO week-numbering year.
+    /// Sunday starting weeks take the number of the Monday that follows.
+    #[default]
+    Iso,
+    /// The week containing January 1st is week 1.
+    /// Weeks are counted in the year they end in, so they are never split.
+    Us,
+    /// Equivalent to chrono's `%U` or `%W`, depending on the week start.
+    /// The first full week is week 1, any days before it are week 0.
+    Simple,
+}
+
+impl WeekStart {
+    /// Number of days passed since the start of the week.
+    pub fn days_since(&self, weekday: Weekday) -> u32 {
+        match self {
+            WeekStart::Monday => weekday.num_days_from_monday(),
+            WeekStart::Sunday => weekday.num_days_from_sunday(),
+        }
+    }
+    /// Returns the first day of the week the date falls in.
+    pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+        date - chrono::Days::new(u64::from(self.days_since(date.weekday())))
+    }
+    /// Returns the week of the month the date falls in, starting at 1.
+    /// Days before the first week start of the month are week 1.
+    pub fn week_of_month(&self, date: NaiveDate) -> u32 {
+        let first = date.with_day(1).unwrap_or(date);
+        (date.day0() + self.days_since(first.weekday())) / 7 + 1
+    }
+}
+
+impl WeekNumbering {
+    /// Returns the year the week is counted in and the week's number.
+    pub fn number(&self, date: NaiveDate, start: WeekStart) -> (i32, u32) {
+        match self {
+            WeekNumbering::Iso => {
+                let date = match start {
+                    WeekStart::Monday => date,
+                    WeekStart::Sunday => date.succ_opt().unwrap_or(date),
+                };
+                (date.iso_week().year(), date.iso_week().week())
+            }
+            WeekNumbering::Us => {
+                let week_start = start.start_of(date);
+                let year = (week_start + chrono::Days::new(6)).year();
+                let first_week = NaiveDate::from_yo_opt(year, 1)
+                    .map(|jan_1| start.start_of(jan_1))
+                    .unwrap_or(week_start);
+                let week = (week_start - first_week).num_days() / 7 + 1;
+                (year, week as u32)
+            }
+            WeekNumbering::Simple => {
+                let days_since = start.days_since(date.weekday());
+                (date.year(), (date.ordinal0() + 7 - days_since) / 7)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_week_numbers() {
+        let desc = "Test week numbering schemes around the new year";
+        let test_cases = [
+            (
+                WeekNumbering::Iso,
+                WeekStart::Monday,
+                (2025, 12, 30),
+                (2026, 1),
+            ),
+            (
+                WeekNumbering::Iso,
+                WeekStart::Monday,
+                (2026, 1, 4),
+                (2026, 1),
+            ),
+            (
+                WeekNumbering::Iso,
+                WeekStart::Sunday,
+                (2026, 1, 4),
+                (2026, 2),
+            ),
+            (
+                WeekNumbering::Us,
+                WeekStart::Sunday,
+                (2025, 12, 27),
+                (2025, 52),
+            ),
+            (
+                WeekNumbering::Us,
+                WeekStart::Sunday,
+                (2025, 12, 30),
+                (2026, 1),
+            ),
+            (
+                WeekNumbering::Us,
+                WeekStart::Sunday,
+                (2026, 1, 4),
+                (2026, 2),
+            ),
+            (
+                WeekNumbering::Us,
+                WeekStart::Monday,
+                (2026, 1, 4),
+                (2026, 1),
+            ),
+            (
+                WeekNumbering::Simple,
+                WeekStart::Sunday,
+                (2026, 1, 3),
+                (2026, 0),
+            ),
+        ];
+        test_cases
+            .into_iter()
+            .for_each(|(numbering, start, (y, m, d), want)| {
+                let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+                let got = numbering.number(date, start);
+                assert_eq!(want, got, "{desc}: {numbering} {start} {date}");
+            });
+    }
+
+    #[test]
+    fn test_simple_matches_strftime() {
+        let desc = "Test simple numbering matches %U and %W";
+        let mut date = NaiveDate::from_ymd_opt(2025, 12, 20).unwrap();
+        for _ in 0..21 {
+            let (_, sunday) = WeekNumbering::Simple.number(date, WeekStart::Sunday);
+            let (_, monday) = WeekNumbering::Simple.number(date, WeekStart::Monday);
+            assert_eq!(
+                date.format("%U").to_string(),
+                format!("{sunday:02}"),
+                "{desc}"
+            );
+            assert_eq!(
+                date.format("%W").to_string(),
+                format!("{monday:02}"),
+                "{desc}"
+            );
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        let desc = "Test week of month follows the week start";
+        let test_cases = [
+            (WeekStart::Monday, (2025, 12, 30), 5),
+            (WeekStart::Sunday, (2025, 12, 30), 5),
+            (WeekStart::Monday, (2026, 1, 4), 1),
+            (WeekStart::Sunday, (2026, 1, 4), 2),
+        ];
+        test_cases.into_iter().for_each(|(start, (y, m, d), want)| {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            assert_eq!(want, start.week_of_month(date), "{desc}: {start} {date}");
+        });
+    }
+}
@@ -0,0 +1,458 @@
+//! End-to-end tests that run the `sb` binary against a temporary vault.
+//! The editor is a fake script that records its working directory
+//! and arguments instead of opening anything.
+#![cfg(unix)]
+use std::{
+    io::Write,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+use tempfile::TempDir;
+
+/// Temporary vault, config file and fake editor for a single test.
+struct Sandbox {
+    dir: TempDir,
+}
+
+impl Sandbox {
+    /// Creates a sandbox whose config file has the passed in TOML
+    /// appended after the vault section.
+    fn new(config: &str) -> anyhow::Result<Self> {
+        let dir = tempfile::tempdir()?;
+        let sandbox = Self { dir };
+        std::fs::create_dir(sandbox.vault())?;
+        std::fs::write(
+            sandbox.config(),
+            format!("[vault]\ndir = {:?}\n{config}", sandbox.vault()),
+        )?;
+        let editor = sandbox.editor();
+        std::fs::write(
+            &editor,
+            "#!/bin/sh\npwd > \"$SB_TEST_RECORD\"\nprintf '%s\\n' \"$@\" >> \"$SB_TEST_RECORD\"\n",
+        )?;
+        std::fs::set_permissions(&editor, std::fs::Permissions::from_mode(0o755))?;
+        Ok(sandbox)
+    }
+    fn vault(&self) -> PathBuf {
+        self.dir.path().join("vault")
+    }
+    fn config(&self) -> PathBuf {
+        self.dir.path().join("config.toml")
+    }
+    fn editor(&self) -> PathBuf {
+        self.dir.path().join("editor.sh")
+    }
+    fn record(&self) -> PathBuf {
+        self.dir.path().join("editor.log")
+    }
+    /// Builds an `sb` command isolated from the user's config files and editor,
+    /// with the clock fixed at `now`.
+    fn command(&self, now: &str) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_sb"));
+        command
+            .current_dir(self.dir.path())
+            .env_clear()
+            .env("PATH", std::env::var_os("PATH").unwrap_or_default())
+            .env("HOME", self.dir.path())
+            .env("XDG_CONFIG_HOME", self.dir.path().join("xdg"))
+            .env("SECOND_BRAIN_CONFIG", self.config())
+            .env("EDITOR", self.editor())
+            .env("SB_TEST_RECORD", self.record())
+            .env("SB_NOW", now)
+            .env("TZ", "UTC");
+        command
+    }
+    /// Runs `sb` with the arguments, failing the test if it exits unsuccessfully.
+    fn run(&self, now: &str, args: &[&str]) -> anyhow::Result<Output> {
+        let output = self.command(now).args(args).output()?;
+        anyhow::ensure!(
+            output.status.success(),
+            "sb {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(output)
+    }
+    /// Lines the fake editor recorded, the working directory first.
+    /// Returns None if the editor wasn't launched.
+    fn editor_record(&self) -> Option<Vec<String>> {
+        let record = std::fs::read_to_string(self.record()).ok()?;
+        Some(record.lines().map(String::from).collect())
+    }
+}
+
+/// Canonical form of a path, so paths compare equal through symlinked temp dirs.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or(path.to_path_buf())
+}
+
+#[test]
+fn test_open_daily_note() -> anyhow::Result<()> {
+    let desc = "Test running sb creates today's note and opens it from the vault";
+    let sandbox = Sandbox::new("[periodical.day]\ndir = \"day\"")?;
+    sandbox.run("2025-12-30T09:00", &[])?;
+
+    let note = sandbox.vault().join("day/2025-12-30.md");
+    let contents = std::fs::read_to_string(&note)?;
+    assert_eq!("[[2025-12-29]] - [[2025-12-31]]\n\n", contents, "{desc}");
+
+    let record = sandbox.editor_record().expect("editor launched");
+    assert_eq!(
+        canonical(&sandbox.vault()),
+        canonical(Path::new(&record[0])),
+        "{desc}: editor cwd"
+    );
+    assert_eq!(
+        vec![note.display().to_string()],
+        record[1..],
+        "{desc}: args"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_open_periodicals() -> anyhow::Result<()> {
+    let desc = "Test periodical notes are named after the fixed clock";
+    let sandbox = Sandbox::new("[time]\nday_starts_at = \"04:00\"\ntimezone = \"UTC\"")?;
+    let test_cases = [
+        ("2025-12-30T09:00", "week", "2026-W01.md"),
+        ("2025-12-30T09:00", "month", "2025-12.md"),
+        ("2026-01-01T03:00", "day", "2025-12-31.md"),
+        ("2026-01-01T03:00", "year", "2025.md"),
+    ];
+    test_cases.iter().try_for_each(|(now, period, file)| {
+        sandbox.run(now, &["periodical", period])?;
+        let note = sandbox.vault().join(file);
+
+        assert!(note.is_file(), "{desc}: {period} at {now}");
+        let record = sandbox.editor_record().expect("editor launched");
+        assert_eq!(note.display().to_string(), record[1], "{desc}: {period}");
+        anyhow::Ok(())
+    })
+}
+
+#[test]
+fn test_open_existing_note() -> anyhow::Result<()> {
+    let desc = "Test existing notes are opened without being rewritten";
+    let sandbox = Sandbox::new("")?;
+    let note = sandbox.vault().join("2025-12-30.md");
+    std::fs::write(&note, "written by hand")?;
+    sandbox.run("2025-12-30T09:00", &["-p", "day"])?;
+
+    assert_eq!("written by hand", std::fs::read_to_string(&note)?, "{desc}");
+    let record = sandbox.editor_record().expect("editor launched");
+    assert_eq!(note.display().to_string(), record[1], "{desc}: args");
+    Ok(())
+}
+
+#[test]
+fn test_template() -> anyhow::Result<()> {
+    let desc = "Test new notes are written with the heading and template";
+    let sandbox = Sandbox::new("[periodical.day]\ntemplate = \"templates/day.md\"")?;
+    std::fs::create_dir(sandbox.vault().join("templates"))?;
+    std::fs::write(sandbox.vault().join("templates/day.md"), "## Tasks\n")?;
+    sandbox.run("2025-12-30T09:00", &[])?;
+
+    let contents = std::fs::read_to_string(sandbox.vault().join("2025-12-30.md"))?;
+    assert_eq!(
+        "[[2025-12-29]] - [[2025-12-31]]\n\n## Tasks\n", contents,
+        "{desc}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_navigation() -> anyhow::Result<()> {
+    let desc = "Test navigating from an existing note prints the target";
+    let sandbox = Sandbox::new("")?;
+    let test_cases = [
+        (["next", "2025-12-31.md"], "2026-01-01.md"),
+        (["up", "2025-12-31.md"], "2026-W01.md"),
+    ];
+    test_cases.iter().try_for_each(|(args, file)| {
+        let output = sandbox.run("2025-12-30T09:00", &[args[0], args[1], "--print"])?;
+        let note = sandbox.vault().join(file);
+
+        assert_eq!(
+            format!("{}\n", note.display()),
+            String::from_utf8(output.stdout)?,
+            "{desc}: {args:?}"
+        );
+        assert!(note.is_file(), "{desc}: {args:?} written");
+        anyhow::Ok(())
+    })?;
+    assert!(sandbox.editor_record().is_none(), "{desc}: editor launched");
+    Ok(())
+}
+
+#[test]
+fn test_dry_run() -> anyhow::Result<()> {
+    let desc = "Test dry runs neither write notes nor launch the editor";
+    let sandbox = Sandbox::new("[periodical.day]\ndir = \"day\"")?;
+    let output = sandbox.run("2025-12-30T09:00", &["--dry-run"])?;
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let note = sandbox.vault().join("day/2025-12-30.md");
+    assert!(
+        stdout.contains(&format!("Would create note: {}", note.display())),
+        "{desc}: {stdout}"
+    );
+    assert!(!sandbox.vault().join("day").exists(), "{desc}: written");
+    assert!(sandbox.editor_record().is_none(), "{desc}: editor launched");
+
+    let output = sandbox.run("2025-12-30T09:00", &["--dry-run", "--json"])?;
+    let reports = String::from_utf8(output.stdout)?
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+    let actions = reports
+        .iter()
+        .map(|report| (report["action"].as_str(), report["path"].as_str()))
+        .collect::<Vec<_>>();
+    let path = note.to_str();
+    assert_eq!(
+        vec![(Some("create-note"), path), (Some("open-note"), path)],
+        actions,
+        "{desc}: json"
+    );
+    assert!(!sandbox.vault().join("day").exists(), "{desc}: written");
+    Ok(())
+}
+
+#[test]
+fn test_missing_config() -> anyhow::Result<()> {
+    let desc = "Test running without a config file fails";
+    let sandbox = Sandbox::new("")?;
+    std::fs::remove_file(sandbox.config())?;
+    let output = sandbox.command("2025-12-30T09:00").output()?;
+
+    assert_eq!(Some(78), output.status.code(), "{desc}");
+    assert!(sandbox.editor_record().is_none(), "{desc}: editor launched");
+
+    let output = sandbox.command("2025-12-30T09:00").arg("--json").output()?;
+    let got = serde_json::from_slice::<serde_json::Value>(&output.stderr)?;
+    let paths = got["paths"].as_array().cloned().unwrap_or_default();
+    assert_eq!("invalid-file", got["kind"], "{desc}: kind");
+    assert_eq!(
+        serde_json::json!(sandbox.config()),
+        got["path"],
+        "{desc}: path"
+    );
+    assert_eq!(Some(&got["path"]), paths.first(), "{desc}: paths");
+    assert!(paths.len() > 1, "{desc}: every candidate {paths:?}");
+    Ok(())
+}
+
+#[test]
+fn test_completions_without_config() -> anyhow::Result<()> {
+    let desc = "Test completions install on machines without a config file";
+    let sandbox = Sandbox::new("")?;
+    std::fs::remove_file(sandbox.config())?;
+    let output = sandbox
+        .command("2025-12-30T09:00")
+        .env_remove("SECOND_BRAIN_CONFIG")
+        .args(["completions", "bash"])
+        .output()?;
+
+    assert!(output.status.success(), "{desc}: {output:?}");
+    assert!(!output.stdout.is_empty(), "{desc}: no script");
+    Ok(())
+}
+
+#[test]
+fn test_config_diagnostics() -> anyhow::Result<()> {
+    let desc = "Test invalid configs point at the offending TOML";
+    let sandbox = Sandbox::new("[periodical.days]\ndir = \"day\"")?;
+    let output = sandbox.command("2025-12-30T09:00").output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    assert_eq!(Some(78), output.status.code(), "{desc}: exit code");
+    let config = sandbox.config().display().to_string();
+    let want = [
+        format!(" --> {config}:3:13"),
+        "3 | [periodical.days]".to_string(),
+        "  |             ^^^^".to_string(),
+        "  = hint: periodical key must be one of day/week/month/year".to_string(),
+    ];
+    want.iter()
+        .for_each(|line| assert!(stderr.contains(line), "{desc}: {line}\n{stderr}"));
+    Ok(())
+}
+
+#[test]
+fn test_runtime_exit_code() -> anyhow::Result<()> {
+    let desc = "Test runtime errors exit with their own code";
+    let sandbox = Sandbox::new("")?;
+    let output = sandbox
+        .command("2025-12-30T09:00")
+        .args(["which", "not-a-note.md"])
+        .output()?;
+
+    assert_eq!(Some(1), output.status.code(), "{desc}");
+    Ok(())
+}
+
+#[test]
+fn test_json_output() -> anyhow::Result<()> {
+    let desc = "Test --json prints informational output as JSON";
+    let sandbox = Sandbox::new("")?;
+    let output = sandbox.run("2025-12-30T09:00", &["which", "2025-12-30.md", "--json"])?;
+    let got = serde_json::from_slice::<serde_json::Value>(&output.stdout)?;
+
+    let path = sandbox.vault().join("2025-12-30.md");
+    let want = serde_json::json!({"period": "day", "date": "2025-12-30", "path": path});
+    assert_eq!(want, got, "{desc}");
+    Ok(())
+}
+
+#[test]
+fn test_json_errors() -> anyhow::Result<()> {
+    let desc = "Test --json prints errors as JSON";
+    let sandbox = Sandbox::new("")?;
+    let output = sandbox
+        .command("2025-12-30T09:00")
+        .args(["--json", "which", "not-a-note.md"])
+        .output()?;
+    let got = serde_json::from_slice::<serde_json::Value>(&output.stderr)?;
+
+    let path = sandbox.vault().join("not-a-note.md");
+    assert_eq!("runtime", got["status"], "{desc}: status");
+    assert_eq!("not-periodical", got["kind"], "{desc}: kind");
+    assert_eq!(serde_json::json!(path), got["path"], "{desc}: path");
+    assert!(got["message"].is_string(), "{desc}: message");
+    Ok(())
+}
+
+#[test]
+fn test_calendar() -> anyhow::Result<()> {
+    let desc = "Test the calendar marks existing notes without colors when piped";
+    let sandbox = Sandbox::new("")?;
+    std::fs::write(sandbox.vault().join("2025-12-30.md"), "")?;
+    std::fs::write(sandbox.vault().join("2026-W01.md"), "")?;
+    let output = sandbox.run("2025-12-30T09:00", &["calendar"])?;
+    let stdout = String::from_utf8(output.stdout)?;
+
+    assert!(stdout.contains("December 2025"), "{desc}: title\n{stdout}");
+    assert!(stdout.contains(" 1* 29  30* 31"), "{desc}: marks\n{stdout}");
+    assert!(!stdout.contains('\x1b'), "{desc}: colors\n{stdout}");
+    Ok(())
+}
+
+#[test]
+fn test_render() -> anyhow::Result<()> {
+    let desc = "Test rendering a note with Obsidian syntax as HTML";
+    let sandbox = Sandbox::new("")?;
+    std::fs::write(
+        sandbox.vault().join("2025-12-30.md"),
+        "[[2025-12-29]] - [[2025-12-31]]\n\n> [!todo]\n> - [ ] ==ship== ![[project#Goal]]\n",
+    )?;
+    std::fs::write(
+        sandbox.vault().join("project.md"),
+        "## Goal\n\nrender notes\n",
+    )?;
+    let output = sandbox.run("2025-12-30T09:00", &["render", "2025-12-30.md"])?;
+    let stdout = String::from_utf8(output.stdout)?;
+
+    [
+        "<title>2025-12-30</title>",
+        "<div class=\"callout\" data-callout=\"todo\">",
+        "<input disabled=\"\" type=\"checkbox\"/>",
+        "<mark>ship</mark>",
+        "<p>render notes</p>",
+    ]
+    .iter()
+    .for_each(|want| assert!(stdout.contains(want), "{desc}: {want}\n{stdout}"));
+    assert!(
+        !stdout.contains("2025-12-29"),
+        "{desc}: navigation\n{stdout}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_open_query() -> anyhow::Result<()> {
+    let desc = "Test fuzzy opening notes by name, asking when ambiguous";
+    let sandbox = Sandbox::new("")?;
+    std::fs::create_dir(sandbox.vault().join("projects"))?;
+    let notes = ["projects/garden.md", "projects/garage.md"].map(|f| sandbox.vault().join(f));
+    notes.iter().try_for_each(|note| std::fs::write(note, ""))?;
+
+    sandbox.run("2025-12-30T09:00", &["open", "garden"])?;
+    let record = sandbox.editor_record().expect("editor launched");
+    assert_eq!(notes[0].display().to_string(), record[1], "{desc}: exact");
+
+    let mut child = sandbox
+        .command("2025-12-30T09:00")
+        .args(["open", "gar"])
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().expect("piped stdin").write_all(b"2\n")?;
+    let output = child.wait_with_output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains(" 2) projects/"), "{desc}: picker\n{stderr}");
+
+    let picked = stderr
+        .lines()
+        .find_map(|line| line.strip_prefix(" 2) "))
+        .expect("second match listed");
+    let record = sandbox.editor_record().expect("editor launched");
+    assert_eq!(
+        sandbox.vault().join(picked).display().to_string(),
+        record[1],
+        "{desc}: picked"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_history() -> anyhow::Result<()> {
+    let desc = "Test opened notes are listed by recent and reopened by last";
+    let sandbox = Sandbox::new("")?;
+    sandbox.run("2025-12-30T09:00", &[])?;
+    sandbox.run("2025-12-31T09:00", &[])?;
+    sandbox.run("2025-12-31T09:00", &["-p", "week"])?;
+    std::fs::remove_file(sandbox.vault().join("2026-W01.md"))?;
+    let history = sandbox
+        .dir
+        .path()
+        .join(".local/state/second-brain/history.jsonl");
+    let history = std::fs::read_to_string(history)?;
+    assert!(
+        history.contains("\"opened\":\"2025-12-30T09:00:00Z\""),
+        "{desc}: opened at SB_NOW\n{history}"
+    );
+
+    let output = sandbox.run("2025-12-31T09:00", &["recent"])?;
+    let want = ["2025-12-31.md", "2025-12-30.md"]
+        .map(|file| format!("{}\n", sandbox.vault().join(file).display()))
+        .concat();
+    assert_eq!(want, String::from_utf8(output.stdout)?, "{desc}: recent");
+
+    std::fs::remove_file(sandbox.record())?;
+    sandbox.run("2026-01-05T09:00", &["last"])?;
+    let record = sandbox.editor_record().expect("editor launched");
+    assert_eq!(
+        sandbox.vault().join("2025-12-31.md").display().to_string(),
+        record[1],
+        "{desc}: last"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_state_dir() -> anyhow::Result<()> {
+    let desc = "Test an invalid state dir is reported instead of dropping the history";
+    let sandbox = Sandbox::new("")?;
+    let output = sandbox
+        .command("2025-12-30T09:00")
+        .env("XDG_STATE_HOME", "state")
+        .arg("recent")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    assert_eq!(Some(78), output.status.code(), "{desc}: exit code");
+    assert!(stderr.contains("XDG_STATE_HOME"), "{desc}: {stderr}");
+    Ok(())
+}